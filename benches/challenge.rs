@@ -0,0 +1,19 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+use dlog_proof::{ChallengeHash, DLogProof};
+
+fn challenge_hash(c: &mut Criterion) {
+    let base_point: Point<Secp256k1> = Point::generator().into();
+    let points: Vec<Point<Secp256k1>> = (0..64).map(|_| &base_point * Scalar::random()).collect();
+
+    let mut group = c.benchmark_group("challenge_64_points");
+    for hash in [ChallengeHash::Sha256, ChallengeHash::Blake3] {
+        group.bench_function(format!("{:?}", hash), |b| {
+            b.iter(|| DLogProof::compute_challenge_with(hash, black_box("session_1"), 1, black_box(&points)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, challenge_hash);
+criterion_main!(benches);
@@ -0,0 +1,26 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+use dlog_proof::{DLogProof, PublicKeyVerifier};
+
+fn repeated_verification(c: &mut Criterion) {
+    let base_point: Point<Secp256k1> = Point::generator().into();
+    let private_key = Scalar::random();
+    let public_key = &base_point * &private_key;
+    let dlog_proof = DLogProof::generate_proof("session_1", 1, private_key, public_key.clone(), base_point.clone());
+    let verifier = PublicKeyVerifier::new(public_key.clone(), base_point.clone());
+
+    let mut group = c.benchmark_group("verify_one_key");
+    group.bench_function("verify_proof", |b| {
+        b.iter(|| dlog_proof.verify_proof(black_box("session_1"), 1, public_key.clone(), base_point.clone()))
+    });
+    group.bench_function("PublicKeyVerifier", |b| {
+        b.iter(|| verifier.verify_proof(black_box(&dlog_proof), "session_1", 1))
+    });
+    group.bench_function("PublicKeyVerifier::new", |b| {
+        b.iter(|| PublicKeyVerifier::new(black_box(public_key.clone()), base_point.clone()))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, repeated_verification);
+criterion_main!(benches);
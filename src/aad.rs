@@ -0,0 +1,145 @@
+use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+
+use crate::hash::AAD_DOMAIN;
+use crate::{ChallengeHash, DLogError, DLogProof};
+
+/// A [`DLogProof`] carrying associated data (e.g. a routing header). The data is not part
+/// of the proven statement, but it is hashed into the challenge, so changing it
+/// invalidates the proof.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AadDLogProof {
+    pub proof: DLogProof,
+    pub aad: Vec<u8>,
+}
+
+impl AadDLogProof {
+    pub fn generate_proof(
+        session_id: &str,
+        participant_id: i32,
+        private_key: Scalar<Secp256k1>,
+        public_key: Point<Secp256k1>,
+        base_point: Point<Secp256k1>,
+        aad: &[u8],
+    ) -> AadDLogProof {
+        let random_scalar = Scalar::random();
+        let commitment = &base_point * &random_scalar;
+        let challenge = DLogProof::expect_challenge(aad_challenge(
+            session_id,
+            participant_id,
+            &public_key,
+            &base_point,
+            &commitment,
+            aad,
+        ));
+
+        let response = DLogProof::response_for(random_scalar, private_key, &challenge);
+
+        AadDLogProof { proof: DLogProof::new(commitment, response), aad: aad.to_vec() }
+    }
+
+    pub fn verify_proof(
+        &self,
+        session_id: &str,
+        participant_id: i32,
+        public_key: Point<Secp256k1>,
+        base_point: Point<Secp256k1>,
+    ) -> Result<bool, DLogError> {
+        let challenge = aad_challenge(
+            session_id,
+            participant_id,
+            &public_key,
+            &base_point,
+            &self.proof.commitment,
+            &self.aad,
+        )?;
+        self.proof.verify_proof_with_challenge(&challenge, public_key, base_point)
+    }
+
+    /// [`DLogProof::to_bytes`] followed by the associated data.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.proof.to_bytes();
+        bytes.extend_from_slice(&self.aad);
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<AadDLogProof, DLogError> {
+        if bytes.len() < DLogProof::SERIALIZED_LEN {
+            return Err(DLogError::InvalidLength { expected: DLogProof::SERIALIZED_LEN, actual: bytes.len() });
+        }
+        let (proof, aad) = bytes.split_at(DLogProof::SERIALIZED_LEN);
+        Ok(AadDLogProof { proof: DLogProof::from_bytes(proof)?, aad: aad.to_vec() })
+    }
+}
+
+// The domain tag and participant id, the length-prefixed session id, the usual challenge
+// points, then the length-prefixed associated data.
+fn aad_challenge(
+    session_id: &str,
+    participant_id: i32,
+    public_key: &Point<Secp256k1>,
+    base_point: &Point<Secp256k1>,
+    commitment: &Point<Secp256k1>,
+    aad: &[u8],
+) -> Result<Scalar<Secp256k1>, DLogError> {
+    let session_len = (session_id.len() as u64).to_be_bytes();
+    let points = [base_point, public_key, commitment].map(|point| point.to_bytes(false));
+    let aad_len = (aad.len() as u64).to_be_bytes();
+    let parts = [&session_len[..], session_id.as_bytes()]
+        .into_iter()
+        .chain(points.iter().map(|point| point.as_ref()))
+        .chain([&aad_len[..], aad]);
+    DLogProof::hash_challenge(ChallengeHash::DEFAULT, AAD_DOMAIN, participant_id, parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aad_proof(aad: &[u8]) -> (AadDLogProof, Point<Secp256k1>) {
+        let base_point = Point::generator();
+        let private_key = Scalar::random();
+        let public_key = base_point * private_key.clone();
+        let proof = AadDLogProof::generate_proof(
+            "session_1",
+            1,
+            private_key,
+            public_key.clone(),
+            base_point.into(),
+            aad,
+        );
+        (proof, public_key)
+    }
+
+    #[test]
+    fn test_aad_tampering_fails() {
+        let base_point = Point::generator();
+        let (proof, public_key) = aad_proof(b"route: node-7");
+        assert_eq!(proof.verify_proof("session_1", 1, public_key.clone(), base_point.into()), Ok(true));
+
+        let mut tampered = proof.clone();
+        tampered.aad = b"route: node-8".to_vec();
+        assert_eq!(tampered.verify_proof("session_1", 1, public_key.clone(), base_point.into()), Ok(false));
+
+        tampered.aad.clear();
+        assert_eq!(tampered.verify_proof("session_1", 1, public_key.clone(), base_point.into()), Ok(false));
+
+        // Nor does the bare proof verify without its associated data.
+        assert_eq!(proof.proof.verify_proof("session_1", 1, public_key, base_point.into()), Ok(false));
+    }
+
+    #[test]
+    fn test_aad_serialization_round_trip() {
+        let base_point = Point::generator();
+        for aad in [&b""[..], &b"route: node-7"[..]] {
+            let (proof, public_key) = aad_proof(aad);
+            let bytes = proof.to_bytes();
+            assert_eq!(bytes.len(), DLogProof::SERIALIZED_LEN + aad.len());
+
+            let restored = AadDLogProof::from_bytes(&bytes).unwrap();
+            assert_eq!(restored.aad, aad);
+            assert_eq!(restored, proof);
+            assert_eq!(restored.verify_proof("session_1", 1, public_key, base_point.into()), Ok(true));
+        }
+        assert!(matches!(AadDLogProof::from_bytes(&[0u8; 10]), Err(DLogError::InvalidLength { .. })));
+    }
+}
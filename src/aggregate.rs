@@ -0,0 +1,177 @@
+use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+
+use crate::hash::AGGREGATE_DOMAIN;
+use crate::{ChallengeHash, DLogError, DLogProof};
+
+impl DLogProof {
+    /// One proof per `(participant_id, private_key, public_key)` entry, all answering a
+    /// single challenge over the session, every entry's id and key, and the sum of the
+    /// commitments. Each proof checks on its own with
+    /// [`DLogProof::verify_aggregatable`], and [`DLogProof::aggregate`] folds them into
+    /// one proof for the sum of the keys.
+    pub fn generate_aggregatable(
+        session_id: &str,
+        entries: &[(i32, Scalar<Secp256k1>, Point<Secp256k1>)],
+        base_point: Point<Secp256k1>,
+    ) -> Vec<DLogProof> {
+        let nonces: Vec<Scalar<Secp256k1>> = entries.iter().map(|_| Scalar::random()).collect();
+        let commitments: Vec<Point<Secp256k1>> = nonces.iter().map(|nonce| &base_point * nonce).collect();
+        let statements: Vec<(i32, Point<Secp256k1>)> =
+            entries.iter().map(|(participant_id, _, public_key)| (*participant_id, public_key.clone())).collect();
+        let challenge = DLogProof::expect_challenge(aggregate_challenge(
+            session_id,
+            &statements,
+            &base_point,
+            &sum(&commitments),
+        ));
+
+        entries
+            .iter()
+            .zip(nonces)
+            .zip(commitments)
+            .map(|(((_, private_key, _), nonce), commitment)| {
+                DLogProof::new(commitment, DLogProof::response_for(nonce, private_key.clone(), &challenge))
+            })
+            .collect()
+    }
+
+    /// Checks every proof from [`DLogProof::generate_aggregatable`] against its
+    /// `(participant_id, public_key)` statement.
+    pub fn verify_aggregatable(
+        session_id: &str,
+        statements: &[(i32, Point<Secp256k1>)],
+        proofs: &[DLogProof],
+        base_point: Point<Secp256k1>,
+    ) -> bool {
+        if statements.is_empty() || statements.len() != proofs.len() {
+            return false;
+        }
+        let commitments: Vec<Point<Secp256k1>> = proofs.iter().map(|proof| proof.commitment.clone()).collect();
+        let challenge = match aggregate_challenge(session_id, statements, &base_point, &sum(&commitments)) {
+            Ok(challenge) => challenge,
+            Err(_) => return false,
+        };
+
+        statements.iter().zip(proofs).all(|((_, public_key), proof)| {
+            matches!(
+                proof.verify_proof_with_challenge(&challenge, public_key.clone(), base_point.clone()),
+                Ok(true)
+            )
+        })
+    }
+
+    /// Sums commitments and responses into a single proof.
+    pub fn aggregate(proofs: &[DLogProof]) -> DLogProof {
+        let commitments: Vec<Point<Secp256k1>> = proofs.iter().map(|proof| proof.commitment.clone()).collect();
+        let response = proofs.iter().fold(Scalar::zero(), |acc, proof| acc + &proof.response);
+        DLogProof::new(sum(&commitments), response)
+    }
+
+    /// Verifies an [`DLogProof::aggregate`] with a single equation against the sum of the
+    /// statements' keys.
+    ///
+    /// On its own this shows knowledge of the discrete log of that sum, not of each key:
+    /// a participant who picks their key after seeing the others' can cancel them out.
+    /// Verify the individual proofs as well, or only aggregate keys that were registered
+    /// with their own proofs, whenever per-key knowledge matters.
+    pub fn aggregate_verify(
+        session_id: &str,
+        statements: &[(i32, Point<Secp256k1>)],
+        aggregate: &DLogProof,
+        base_point: Point<Secp256k1>,
+    ) -> bool {
+        if statements.is_empty() {
+            return false;
+        }
+        let challenge = match aggregate_challenge(session_id, statements, &base_point, &aggregate.commitment) {
+            Ok(challenge) => challenge,
+            Err(_) => return false,
+        };
+        let public_keys: Vec<Point<Secp256k1>> =
+            statements.iter().map(|(_, public_key)| public_key.clone()).collect();
+
+        matches!(aggregate.verify_proof_with_challenge(&challenge, sum(&public_keys), base_point), Ok(true))
+    }
+}
+
+fn sum(points: &[Point<Secp256k1>]) -> Point<Secp256k1> {
+    points.iter().fold(Point::zero(), |acc, point| acc + point)
+}
+
+fn aggregate_challenge(
+    session_id: &str,
+    statements: &[(i32, Point<Secp256k1>)],
+    base_point: &Point<Secp256k1>,
+    commitment_sum: &Point<Secp256k1>,
+) -> Result<Scalar<Secp256k1>, DLogError> {
+    let session_len = (session_id.len() as u64).to_be_bytes();
+    let count = (statements.len() as u64).to_be_bytes();
+    let base_point = base_point.to_bytes(false);
+    let commitment_sum = commitment_sum.to_bytes(false);
+    let encoded: Vec<_> = statements
+        .iter()
+        .map(|(participant_id, public_key)| (participant_id.to_be_bytes(), public_key.to_bytes(false)))
+        .collect();
+
+    let mut parts: Vec<&[u8]> =
+        vec![AGGREGATE_DOMAIN, &session_len, session_id.as_bytes(), &count, base_point.as_ref()];
+    for (participant_id, public_key) in &encoded {
+        parts.push(participant_id);
+        parts.push(public_key.as_ref());
+    }
+    parts.push(commitment_sum.as_ref());
+
+    DLogProof::challenge_from_digest(&ChallengeHash::DEFAULT.digest(parts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries(n: i32) -> Vec<(i32, Scalar<Secp256k1>, Point<Secp256k1>)> {
+        let base_point = Point::generator();
+        (1..=n)
+            .map(|participant_id| {
+                let private_key = Scalar::random();
+                let public_key = base_point * private_key.clone();
+                (participant_id, private_key, public_key)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_aggregatable_proofs() {
+        let base_point = Point::generator();
+        let entries = entries(4);
+        let statements: Vec<(i32, Point<Secp256k1>)> =
+            entries.iter().map(|(participant_id, _, public_key)| (*participant_id, public_key.clone())).collect();
+
+        let proofs = DLogProof::generate_aggregatable("session_1", &entries, base_point.into());
+        assert_eq!(proofs.len(), entries.len());
+        assert!(DLogProof::verify_aggregatable("session_1", &statements, &proofs, base_point.into()));
+
+        let aggregate = DLogProof::aggregate(&proofs);
+        assert!(DLogProof::aggregate_verify("session_1", &statements, &aggregate, base_point.into()));
+        assert!(!DLogProof::aggregate_verify("session_2", &statements, &aggregate, base_point.into()));
+
+        let mut swapped_ids = statements.clone();
+        swapped_ids[0].0 = 9;
+        assert!(!DLogProof::verify_aggregatable("session_1", &swapped_ids, &proofs, base_point.into()));
+        assert!(!DLogProof::aggregate_verify("session_1", &swapped_ids, &aggregate, base_point.into()));
+    }
+
+    #[test]
+    fn test_aggregatable_proofs_fail_wrong_key() {
+        let base_point = Point::generator();
+        let entries = entries(3);
+        let mut statements: Vec<(i32, Point<Secp256k1>)> =
+            entries.iter().map(|(participant_id, _, public_key)| (*participant_id, public_key.clone())).collect();
+        let proofs = DLogProof::generate_aggregatable("session_1", &entries, base_point.into());
+
+        statements[1].1 = base_point * Scalar::random();
+        assert!(!DLogProof::verify_aggregatable("session_1", &statements, &proofs, base_point.into()));
+        let aggregate = DLogProof::aggregate(&proofs);
+        assert!(!DLogProof::aggregate_verify("session_1", &statements, &aggregate, base_point.into()));
+        assert!(!DLogProof::verify_aggregatable("session_1", &statements[..2], &proofs, base_point.into()));
+    }
+}
@@ -0,0 +1,231 @@
+use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+
+use crate::hash::AND_DOMAIN;
+use crate::{ChallengeHash, DLogError, DLogProof};
+
+/// Collects `(witness, public_key, base_point)` statements for an [`AndProof`].
+pub struct AndProofBuilder {
+    session_id: String,
+    participant_id: i32,
+    statements: Vec<(Scalar<Secp256k1>, Point<Secp256k1>, Point<Secp256k1>)>,
+}
+
+/// Conjunction of DLog statements under one challenge over the statement count, every
+/// base point and public key, then every commitment, so no sub-proof can be swapped out
+/// on its own. The challenge has its own domain tag, so no sub-proof, not even of a
+/// single-statement conjunction, verifies as a plain [`DLogProof`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AndProof {
+    /// One commitment/response pair per statement, in the order they were added.
+    pub proofs: Vec<DLogProof>,
+}
+
+impl AndProofBuilder {
+    pub fn new(session_id: &str, participant_id: i32) -> AndProofBuilder {
+        AndProofBuilder { session_id: session_id.to_string(), participant_id, statements: Vec::new() }
+    }
+
+    pub fn statement(
+        mut self,
+        witness: Scalar<Secp256k1>,
+        public_key: Point<Secp256k1>,
+        base_point: Point<Secp256k1>,
+    ) -> AndProofBuilder {
+        self.statements.push((witness, public_key, base_point));
+        self
+    }
+
+    /// Fails with [`DLogError::EmptyStatement`] if no statement was added.
+    pub fn prove(self) -> Result<AndProof, DLogError> {
+        if self.statements.is_empty() {
+            return Err(DLogError::EmptyStatement);
+        }
+        let nonces: Vec<Scalar<Secp256k1>> = self.statements.iter().map(|_| Scalar::random()).collect();
+        let commitments: Vec<Point<Secp256k1>> = self
+            .statements
+            .iter()
+            .zip(&nonces)
+            .map(|((_, _, base_point), nonce)| base_point * nonce)
+            .collect();
+        let keys: Vec<(Point<Secp256k1>, Point<Secp256k1>)> = self
+            .statements
+            .iter()
+            .map(|(_, public_key, base_point)| (public_key.clone(), base_point.clone()))
+            .collect();
+        let challenge = and_challenge(&self.session_id, self.participant_id, &keys, &commitments)?;
+
+        let proofs = self
+            .statements
+            .into_iter()
+            .zip(nonces)
+            .zip(commitments)
+            .map(|(((witness, _, _), nonce), commitment)| {
+                DLogProof::new(commitment, DLogProof::response_for(nonce, witness, &challenge))
+            })
+            .collect();
+        Ok(AndProof { proofs })
+    }
+}
+
+impl AndProof {
+    /// Checks every relation against its `(public_key, base_point)`, given in the order
+    /// the statements were added. Fails with [`DLogError::EmptyStatement`] if
+    /// `statements` is empty.
+    pub fn verify(
+        &self,
+        session_id: &str,
+        participant_id: i32,
+        statements: &[(Point<Secp256k1>, Point<Secp256k1>)],
+    ) -> Result<bool, DLogError> {
+        if statements.is_empty() {
+            return Err(DLogError::EmptyStatement);
+        }
+        if statements.len() != self.proofs.len() {
+            return Ok(false);
+        }
+        let commitments: Vec<Point<Secp256k1>> = self.proofs.iter().map(|proof| proof.commitment.clone()).collect();
+        let challenge = and_challenge(session_id, participant_id, statements, &commitments)?;
+
+        for ((public_key, base_point), proof) in statements.iter().zip(&self.proofs) {
+            if !proof.verify_proof_with_challenge(&challenge, public_key.clone(), base_point.clone())? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+// The domain tag and participant id, the length-prefixed session id, the statement count,
+// each statement's base point and public key, then the commitments.
+fn and_challenge(
+    session_id: &str,
+    participant_id: i32,
+    statements: &[(Point<Secp256k1>, Point<Secp256k1>)],
+    commitments: &[Point<Secp256k1>],
+) -> Result<Scalar<Secp256k1>, DLogError> {
+    let session_len = (session_id.len() as u64).to_be_bytes();
+    let count = (statements.len() as u64).to_be_bytes();
+    let mut points = Vec::with_capacity(3 * statements.len());
+    for (public_key, base_point) in statements {
+        points.push(base_point.to_bytes(false));
+        points.push(public_key.to_bytes(false));
+    }
+    points.extend(commitments.iter().map(|commitment| commitment.to_bytes(false)));
+
+    let parts = [&session_len[..], session_id.as_bytes(), &count]
+        .into_iter()
+        .chain(points.iter().map(|point| point.as_ref()));
+    DLogProof::hash_challenge(ChallengeHash::DEFAULT, AND_DOMAIN, participant_id, parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ChaumPedersenProof;
+
+    type Statement = (Scalar<Secp256k1>, Point<Secp256k1>, Point<Secp256k1>);
+
+    fn statements(n: usize) -> Vec<Statement> {
+        let generator: Point<Secp256k1> = Point::generator().into();
+        (0..n)
+            .map(|_| {
+                let base_point = &generator * Scalar::random();
+                let witness = Scalar::random();
+                let public_key = &base_point * &witness;
+                (witness, public_key, base_point)
+            })
+            .collect()
+    }
+
+    fn prove(statements: &[Statement]) -> Result<AndProof, DLogError> {
+        statements
+            .iter()
+            .fold(AndProofBuilder::new("session_1", 1), |builder, (witness, public_key, base_point)| {
+                builder.statement(witness.clone(), public_key.clone(), base_point.clone())
+            })
+            .prove()
+    }
+
+    fn public(statements: &[Statement]) -> Vec<(Point<Secp256k1>, Point<Secp256k1>)> {
+        statements.iter().map(|(_, public_key, base_point)| (public_key.clone(), base_point.clone())).collect()
+    }
+
+    #[test]
+    fn test_and_proof() {
+        let statements = statements(3);
+        let and_proof = prove(&statements).unwrap();
+        assert_eq!(and_proof.proofs.len(), 3);
+        assert_eq!(and_proof.verify("session_1", 1, &public(&statements)), Ok(true));
+        assert_eq!(and_proof.verify("session_2", 1, &public(&statements)), Ok(false));
+        assert_eq!(and_proof.verify("session_1", 1, &public(&statements[..2])), Ok(false));
+
+        // Sub-proofs are bound to the whole conjunction.
+        let mut reordered = public(&statements);
+        reordered.swap(0, 1);
+        let mut swapped = and_proof.clone();
+        swapped.proofs.swap(0, 1);
+        assert_eq!(swapped.verify("session_1", 1, &reordered), Ok(false));
+        let (_, public_key, base_point) = statements[0].clone();
+        assert!(!and_proof.proofs[0].verify_proof("session_1", 1, public_key, base_point).unwrap());
+    }
+
+    #[test]
+    fn test_and_proof_fail_invalid_statement() {
+        let mut statements = statements(3);
+        statements[1].0 = Scalar::random();
+        let and_proof = prove(&statements).unwrap();
+        assert_eq!(and_proof.verify("session_1", 1, &public(&statements)), Ok(false));
+
+        assert!(matches!(prove(&[]), Err(DLogError::EmptyStatement)));
+        assert_eq!(and_proof.verify("session_1", 1, &[]), Err(DLogError::EmptyStatement));
+    }
+
+    #[test]
+    fn test_single_statement_is_not_plain_proof() {
+        let statements = statements(1);
+        let and_proof = prove(&statements).unwrap();
+        assert_eq!(and_proof.verify("session_1", 1, &public(&statements)), Ok(true));
+        let (_, public_key, base_point) = statements[0].clone();
+        assert!(!and_proof.proofs[0].verify_proof("session_1", 1, public_key, base_point).unwrap());
+    }
+
+    #[test]
+    fn test_and_proof_and_chaum_pedersen_reject_each_other() {
+        // With `h = a`, the Chaum-Pedersen points `[g, h, a, b, commitment_g, commitment_h]`
+        // line up with an AndProof over `(a, g)` and `(b, a)`, whose equations then coincide.
+        let g: Point<Secp256k1> = Point::generator().into();
+        let x = Scalar::random();
+        let a = &g * &x;
+        let b = &a * &x;
+        let statements = [(a.clone(), g.clone()), (b.clone(), a.clone())];
+
+        let (chaum_pedersen, _, _) = ChaumPedersenProof::generate("session_1", 1, x.clone(), g.clone(), a.clone());
+        let verify_chaum_pedersen = |proof: &ChaumPedersenProof| {
+            ChaumPedersenProof::verify("session_1", 1, a.clone(), b.clone(), proof, g.clone(), a.clone())
+        };
+        assert!(verify_chaum_pedersen(&chaum_pedersen));
+        let as_and = AndProof {
+            proofs: vec![
+                DLogProof::new(chaum_pedersen.commitment_g.clone(), chaum_pedersen.response.clone()),
+                DLogProof::new(chaum_pedersen.commitment_h.clone(), chaum_pedersen.response.clone()),
+            ],
+        };
+        assert_eq!(as_and.verify("session_1", 1, &statements), Ok(false));
+
+        // An AndProof sharing one nonce across both statements has Chaum-Pedersen's shape.
+        let nonce = Scalar::random();
+        let commitments = [&g * &nonce, &a * &nonce];
+        let challenge = and_challenge("session_1", 1, &statements, &commitments).unwrap();
+        let response = DLogProof::response_for(nonce, x, &challenge);
+        let and_proof = AndProof {
+            proofs: commitments.iter().map(|commitment| DLogProof::new(commitment.clone(), response.clone())).collect(),
+        };
+        assert_eq!(and_proof.verify("session_1", 1, &statements), Ok(true));
+        let as_chaum_pedersen = ChaumPedersenProof {
+            commitment_g: commitments[0].clone(),
+            commitment_h: commitments[1].clone(),
+            response,
+        };
+        assert!(!verify_chaum_pedersen(&as_chaum_pedersen));
+    }
+}
@@ -0,0 +1,90 @@
+use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+
+use crate::hash::ANONYMOUS_DOMAIN;
+use crate::{ChallengeHash, DLogError, DLogProof};
+
+impl DLogProof {
+    /// [`DLogProof::generate_proof`] for callers without a session concept.
+    pub fn generate_proof_anonymous(
+        participant_id: i32,
+        private_key: Scalar<Secp256k1>,
+        public_key: Point<Secp256k1>,
+        base_point: Point<Secp256k1>,
+    ) -> DLogProof {
+        let random_scalar = Scalar::random();
+        let commitment = &base_point * &random_scalar;
+        let challenge =
+            DLogProof::expect_challenge(anonymous_challenge(participant_id, &public_key, &base_point, &commitment));
+
+        let response = DLogProof::response_for(random_scalar, private_key, &challenge);
+
+        DLogProof::new(commitment, response)
+    }
+
+    pub fn verify_proof_anonymous(
+        &self,
+        participant_id: i32,
+        public_key: Point<Secp256k1>,
+        base_point: Point<Secp256k1>,
+    ) -> Result<bool, DLogError> {
+        let challenge = anonymous_challenge(participant_id, &public_key, &base_point, &self.commitment)?;
+        self.verify_proof_with_challenge(&challenge, public_key, base_point)
+    }
+}
+
+// The domain tag is hashed where the session id would be.
+fn anonymous_challenge(
+    participant_id: i32,
+    public_key: &Point<Secp256k1>,
+    base_point: &Point<Secp256k1>,
+    commitment: &Point<Secp256k1>,
+) -> Result<Scalar<Secp256k1>, DLogError> {
+    let points = [base_point, public_key, commitment].map(|point| point.to_bytes(false));
+    DLogProof::hash_challenge(
+        ChallengeHash::DEFAULT,
+        ANONYMOUS_DOMAIN,
+        participant_id,
+        points.iter().map(|point| point.as_ref()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_anonymous_proof() {
+        let base_point = Point::generator();
+        let private_key = Scalar::random();
+        let public_key = base_point * private_key.clone();
+
+        let dlog_proof = DLogProof::generate_proof_anonymous(1, private_key, public_key.clone(), base_point.into());
+        assert_eq!(dlog_proof.verify_proof_anonymous(1, public_key.clone(), base_point.into()), Ok(true));
+        assert_eq!(dlog_proof.verify_proof_anonymous(2, public_key.clone(), base_point.into()), Ok(false));
+    }
+
+    #[test]
+    fn test_anonymous_and_session_modes_reject_each_other() {
+        let base_point = Point::generator();
+        let private_key = Scalar::random();
+        let public_key = base_point * private_key.clone();
+
+        let anonymous =
+            DLogProof::generate_proof_anonymous(1, private_key.clone(), public_key.clone(), base_point.into());
+        let lossy_tag = String::from_utf8_lossy(ANONYMOUS_DOMAIN).into_owned();
+        for session_id in ["", "dlog_proof/anonymous", lossy_tag.as_str()] {
+            assert_eq!(anonymous.verify_proof(session_id, 1, public_key.clone(), base_point.into()), Ok(false));
+        }
+
+        for session_id in ["", "session_1"] {
+            let with_session = DLogProof::generate_proof(
+                session_id,
+                1,
+                private_key.clone(),
+                public_key.clone(),
+                base_point.into(),
+            );
+            assert_eq!(with_session.verify_proof_anonymous(1, public_key.clone(), base_point.into()), Ok(false));
+        }
+    }
+}
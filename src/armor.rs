@@ -0,0 +1,75 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+use crate::{DLogError, DLogProof};
+
+const BEGIN: &str = "-----BEGIN DLOG PROOF-----";
+const END: &str = "-----END DLOG PROOF-----";
+const LINE_WIDTH: usize = 64;
+
+impl DLogProof {
+    /// PEM-style armored text around the base64 of [`DLogProof::to_bytes`].
+    pub fn to_armored(&self) -> String {
+        let body = STANDARD.encode(self.to_bytes());
+        let mut armored = String::from(BEGIN);
+        armored.push('\n');
+        for line in body.as_bytes().chunks(LINE_WIDTH) {
+            // base64 output is ASCII, so chunk boundaries are valid UTF-8.
+            armored.push_str(std::str::from_utf8(line).unwrap());
+            armored.push('\n');
+        }
+        armored.push_str(END);
+        armored.push('\n');
+        armored
+    }
+
+    /// Parses [`DLogProof::to_armored`] output; surrounding whitespace is ignored.
+    pub fn from_armored(armored: &str) -> Result<DLogProof, DLogError> {
+        let armored = armored.trim();
+        let body = armored
+            .strip_prefix(BEGIN)
+            .and_then(|rest| rest.strip_suffix(END))
+            .ok_or(DLogError::InvalidArmor)?;
+        let body: String = body.split_whitespace().collect();
+        let bytes = STANDARD.decode(body).map_err(|_| DLogError::InvalidArmor)?;
+        DLogProof::from_bytes(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use curv::elliptic::curves::{Point, Scalar};
+
+    use super::*;
+
+    fn proof() -> DLogProof {
+        let base_point = Point::generator();
+        let private_key = Scalar::random();
+        let public_key = base_point * private_key.clone();
+        DLogProof::generate_proof("session_1", 1, private_key, public_key, base_point.into())
+    }
+
+    #[test]
+    fn test_armored_round_trip() {
+        let dlog_proof = proof();
+        let armored = dlog_proof.to_armored();
+        assert!(armored.starts_with(BEGIN));
+
+        let padded = format!("\n\n  {}  \n", armored);
+        assert_eq!(DLogProof::from_armored(&padded).unwrap(), dlog_proof);
+    }
+
+    #[test]
+    fn test_armored_rejects_bad_headers() {
+        let armored = proof().to_armored();
+
+        let missing_header = armored.replace(BEGIN, "");
+        assert_eq!(DLogProof::from_armored(&missing_header), Err(DLogError::InvalidArmor));
+
+        let wrong_header = armored.replace(BEGIN, "-----BEGIN PUBLIC KEY-----");
+        assert_eq!(DLogProof::from_armored(&wrong_header), Err(DLogError::InvalidArmor));
+
+        let missing_footer = armored.replace(END, "");
+        assert_eq!(DLogProof::from_armored(&missing_footer), Err(DLogError::InvalidArmor));
+    }
+}
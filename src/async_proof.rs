@@ -0,0 +1,47 @@
+use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+
+use crate::{DLogError, DLogProof};
+
+impl DLogProof {
+    /// [`DLogProof::generate_proof`] on tokio's blocking pool, so neither the OS RNG nor
+    /// the curve arithmetic runs on an async worker thread.
+    pub async fn generate_proof_async(
+        session_id: String,
+        participant_id: i32,
+        private_key: Scalar<Secp256k1>,
+        public_key: Point<Secp256k1>,
+        base_point: Point<Secp256k1>,
+    ) -> Result<DLogProof, DLogError> {
+        tokio::task::spawn_blocking(move || {
+            DLogProof::generate_proof(&session_id, participant_id, private_key, public_key, base_point)
+        })
+        .await
+        .map_err(|_| DLogError::TaskFailed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_generate_proof_async() {
+        let session_id = "session_1";
+        let participant_id = 1;
+
+        let base_point = Point::generator();
+        let private_key = Scalar::random();
+        let public_key = base_point * private_key.clone();
+
+        let dlog_proof = DLogProof::generate_proof_async(
+            session_id.to_string(),
+            participant_id,
+            private_key,
+            public_key.clone(),
+            base_point.into(),
+        )
+        .await
+        .unwrap();
+        assert!(dlog_proof.verify_proof(session_id, participant_id, public_key, base_point.into()).unwrap());
+    }
+}
@@ -0,0 +1,170 @@
+use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+
+use crate::hash::CHAIN_DOMAIN;
+use crate::{ChallengeHash, DLogError, DLogProof, DLogStatement};
+
+/// One entry of a hash-linked proof log: a proof for `statement` whose challenge also
+/// binds `prev_hash`, the [`ChainedProof::hash`] of the entry before it (or a genesis
+/// hash for the first entry).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChainedProof {
+    pub statement: DLogStatement,
+    pub proof: DLogProof,
+    pub prev_hash: [u8; 32],
+}
+
+impl ChainedProof {
+    pub fn generate_proof(
+        prev_hash: [u8; 32],
+        statement: DLogStatement,
+        private_key: Scalar<Secp256k1>,
+    ) -> ChainedProof {
+        let random_scalar = Scalar::random();
+        let commitment = &statement.base_point * &random_scalar;
+        let challenge = DLogProof::expect_challenge(chained_challenge(&statement, &commitment, &prev_hash));
+
+        let proof = DLogProof::new(commitment, DLogProof::response_for(random_scalar, private_key, &challenge));
+        ChainedProof { statement, proof, prev_hash }
+    }
+
+    pub fn verify_proof(&self) -> Result<bool, DLogError> {
+        let challenge = chained_challenge(&self.statement, &self.proof.commitment, &self.prev_hash)?;
+        self.proof.verify_proof_with_challenge(
+            &challenge,
+            self.statement.public_key.clone(),
+            self.statement.base_point.clone(),
+        )
+    }
+
+    /// SHA-256 of `prev_hash` followed by [`DLogProof::to_bytes`]; the next entry's
+    /// `prev_hash`.
+    pub fn hash(&self) -> [u8; 32] {
+        ChallengeHash::Sha256.digest([&self.prev_hash[..], &self.proof.to_bytes()])
+    }
+
+    /// Checks that the first entry links to `genesis_hash`, each later entry to the one
+    /// before it, and that every proof verifies. Any reordered, dropped or altered entry
+    /// breaks a link or a proof.
+    pub fn verify_chain(proofs: &[ChainedProof], genesis_hash: &[u8; 32]) -> bool {
+        let mut expected = *genesis_hash;
+        for entry in proofs {
+            if entry.prev_hash != expected || !matches!(entry.verify_proof(), Ok(true)) {
+                return false;
+            }
+            expected = entry.hash();
+        }
+        true
+    }
+}
+
+// The domain tag and participant id, the length-prefixed session id, the usual challenge
+// points, then the previous entry's hash.
+fn chained_challenge(
+    statement: &DLogStatement,
+    commitment: &Point<Secp256k1>,
+    prev_hash: &[u8; 32],
+) -> Result<Scalar<Secp256k1>, DLogError> {
+    let session_id = statement.session_id.as_bytes();
+    let session_len = (session_id.len() as u64).to_be_bytes();
+    let points = [&statement.base_point, &statement.public_key, commitment].map(|point| point.to_bytes(false));
+    let parts = [&session_len[..], session_id]
+        .into_iter()
+        .chain(points.iter().map(|point| point.as_ref()))
+        .chain([&prev_hash[..]]);
+    DLogProof::hash_challenge(ChallengeHash::DEFAULT, CHAIN_DOMAIN, statement.participant_id, parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AadDLogProof;
+
+    fn chain(length: i32) -> (Vec<ChainedProof>, [u8; 32]) {
+        let genesis_hash = [7u8; 32];
+        let base_point: Point<Secp256k1> = Point::generator().into();
+        let private_key = Scalar::random();
+
+        let mut proofs: Vec<ChainedProof> = Vec::new();
+        for participant_id in 0..length {
+            let statement = DLogStatement {
+                session_id: format!("audit_{}", participant_id),
+                participant_id,
+                public_key: &base_point * &private_key,
+                base_point: base_point.clone(),
+            };
+            let prev_hash = proofs.last().map_or(genesis_hash, ChainedProof::hash);
+            proofs.push(ChainedProof::generate_proof(prev_hash, statement, private_key.clone()));
+        }
+        (proofs, genesis_hash)
+    }
+
+    #[test]
+    fn test_verify_chain() {
+        let (proofs, genesis_hash) = chain(4);
+        assert!(proofs.iter().all(|entry| entry.verify_proof() == Ok(true)));
+        assert!(ChainedProof::verify_chain(&proofs, &genesis_hash));
+        assert!(ChainedProof::verify_chain(&proofs[..2], &genesis_hash));
+        assert!(ChainedProof::verify_chain(&[], &genesis_hash));
+        assert!(!ChainedProof::verify_chain(&proofs, &[0u8; 32]));
+    }
+
+    #[test]
+    fn test_verify_chain_rejects_broken_links() {
+        let (proofs, genesis_hash) = chain(4);
+
+        let mut reordered = proofs.clone();
+        reordered.swap(1, 2);
+        assert!(!ChainedProof::verify_chain(&reordered, &genesis_hash));
+
+        let mut dropped = proofs.clone();
+        dropped.remove(1);
+        assert!(!ChainedProof::verify_chain(&dropped, &genesis_hash));
+
+        // Relinking an entry to a different predecessor invalidates its proof.
+        let mut relinked = proofs.clone();
+        relinked[2].prev_hash = relinked[0].hash();
+        relinked.remove(1);
+        assert_eq!(relinked[1].verify_proof(), Ok(false));
+        assert!(!ChainedProof::verify_chain(&relinked, &genesis_hash));
+
+        let mut tampered = proofs;
+        tampered[1].statement.session_id = "audit_9".to_string();
+        assert!(!ChainedProof::verify_chain(&tampered, &genesis_hash));
+    }
+
+    #[test]
+    fn test_chain_entry_is_not_an_aad_proof() {
+        let base_point: Point<Secp256k1> = Point::generator().into();
+        let private_key = Scalar::random();
+        let statement = DLogStatement {
+            session_id: "audit_0".to_string(),
+            participant_id: 0,
+            public_key: &base_point * &private_key,
+            base_point: base_point.clone(),
+        };
+
+        // 24 bytes of AAD after their 8-byte length fill exactly the 32 bytes of a prev_hash.
+        let aad = [9u8; 24];
+        let aad_proof = AadDLogProof::generate_proof(
+            &statement.session_id,
+            statement.participant_id,
+            private_key.clone(),
+            statement.public_key.clone(),
+            base_point,
+            &aad,
+        );
+        let mut prev_hash = [0u8; 32];
+        prev_hash[..8].copy_from_slice(&(aad.len() as u64).to_be_bytes());
+        prev_hash[8..].copy_from_slice(&aad);
+        let entry = ChainedProof { statement: statement.clone(), proof: aad_proof.proof.clone(), prev_hash };
+        assert_eq!(entry.verify_proof(), Ok(false));
+        assert!(!ChainedProof::verify_chain(&[entry], &prev_hash));
+
+        let chained = ChainedProof::generate_proof(prev_hash, statement.clone(), private_key);
+        let as_aad = AadDLogProof { proof: chained.proof, aad: aad.to_vec() };
+        assert_eq!(
+            as_aad.verify_proof(&statement.session_id, 0, statement.public_key, statement.base_point),
+            Ok(false)
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+
+use crate::hash::CHAUM_PEDERSEN_DOMAIN;
+use crate::{ChallengeHash, DLogError, DLogProof};
+
+/// Chaum-Pedersen proof that `A = x·g` and `B = x·h` share the exponent `x`, i.e.
+/// `log_g(A) == log_h(B)`, in the layout DLEQ-based VRFs use: one commitment per
+/// generator, one challenge over `[g, h, A, B, commitment_g, commitment_h]` (under its own
+/// domain tag), and one response.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChaumPedersenProof {
+    pub commitment_g: Point<Secp256k1>,
+    pub commitment_h: Point<Secp256k1>,
+    pub response: Scalar<Secp256k1>,
+}
+
+impl ChaumPedersenProof {
+    /// Returns the proof together with `A = x·g` and `B = x·h`.
+    pub fn generate(
+        session_id: &str,
+        participant_id: i32,
+        x: Scalar<Secp256k1>,
+        g: Point<Secp256k1>,
+        h: Point<Secp256k1>,
+    ) -> (ChaumPedersenProof, Point<Secp256k1>, Point<Secp256k1>) {
+        let (a, b) = (&g * &x, &h * &x);
+        let nonce = Scalar::random();
+        let (commitment_g, commitment_h) = (&g * &nonce, &h * &nonce);
+        let challenge = DLogProof::expect_challenge(chaum_pedersen_challenge(
+            session_id,
+            participant_id,
+            [&g, &h, &a, &b, &commitment_g, &commitment_h],
+        ));
+
+        let response = DLogProof::response_for(nonce, x, &challenge);
+        (ChaumPedersenProof { commitment_g, commitment_h, response }, a, b)
+    }
+
+    /// Checks `proof` shows `a` and `b` have the same discrete log to `g` and `h`.
+    pub fn verify(
+        session_id: &str,
+        participant_id: i32,
+        a: Point<Secp256k1>,
+        b: Point<Secp256k1>,
+        proof: &ChaumPedersenProof,
+        g: Point<Secp256k1>,
+        h: Point<Secp256k1>,
+    ) -> bool {
+        if proof.commitment_g.is_zero() || proof.commitment_h.is_zero() {
+            return false;
+        }
+        let challenge = match chaum_pedersen_challenge(
+            session_id,
+            participant_id,
+            [&g, &h, &a, &b, &proof.commitment_g, &proof.commitment_h],
+        ) {
+            Ok(challenge) => challenge,
+            Err(_) => return false,
+        };
+
+        g * &proof.response == &proof.commitment_g + a * &challenge
+            && h * &proof.response == &proof.commitment_h + b * challenge
+    }
+}
+
+// The domain tag and participant id, the length-prefixed session id, then the points.
+fn chaum_pedersen_challenge(
+    session_id: &str,
+    participant_id: i32,
+    points: [&Point<Secp256k1>; 6],
+) -> Result<Scalar<Secp256k1>, DLogError> {
+    let session_len = (session_id.len() as u64).to_be_bytes();
+    let points = points.map(|point| point.to_bytes(false));
+    let parts = [&session_len[..], session_id.as_bytes()]
+        .into_iter()
+        .chain(points.iter().map(|point| point.as_ref()));
+    DLogProof::hash_challenge(ChallengeHash::DEFAULT, CHAUM_PEDERSEN_DOMAIN, participant_id, parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chaum_pedersen_proof() {
+        let g: Point<Secp256k1> = Point::generator().into();
+        let h = Point::base_point2().clone();
+        let x = Scalar::random();
+
+        let (proof, a, b) = ChaumPedersenProof::generate("session_1", 1, x.clone(), g.clone(), h.clone());
+        assert_eq!((&a, &b), (&(&g * &x), &(&h * &x)));
+        assert!(ChaumPedersenProof::verify("session_1", 1, a.clone(), b.clone(), &proof, g.clone(), h.clone()));
+        assert!(!ChaumPedersenProof::verify("session_1", 2, a.clone(), b.clone(), &proof, g.clone(), h.clone()));
+        assert!(!ChaumPedersenProof::verify("session_1", 1, b, a, &proof, g, h));
+    }
+
+    #[test]
+    fn test_chaum_pedersen_proof_fail_different_exponent() {
+        let g: Point<Secp256k1> = Point::generator().into();
+        let h = Point::base_point2().clone();
+        let x = Scalar::random();
+        let a = &g * &x;
+        let b = &h * Scalar::random();
+
+        let (proof, _, _) = ChaumPedersenProof::generate("session_1", 1, x, g.clone(), h.clone());
+        assert!(!ChaumPedersenProof::verify("session_1", 1, a, b, &proof, g, h));
+    }
+}
@@ -0,0 +1,107 @@
+use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+
+use crate::DLogProof;
+
+/// A `DLogProof` stored as `(challenge, response)`; the commitment is recomputed as
+/// `response·base_point - challenge·public_key` during verification.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompressedDLogProof {
+    pub challenge: Scalar<Secp256k1>,
+    pub response: Scalar<Secp256k1>,
+}
+
+impl CompressedDLogProof {
+    /// Compresses `proof`, deriving its challenge from the context it was generated for.
+    pub fn from_proof(
+        proof: &DLogProof,
+        session_id: &str,
+        participant_id: i32,
+        public_key: Point<Secp256k1>,
+        base_point: Point<Secp256k1>,
+    ) -> CompressedDLogProof {
+        let challenge = DLogProof::expect_challenge(DLogProof::compute_challenge(
+            session_id,
+            participant_id,
+            vec![base_point, public_key, proof.commitment.clone()],
+        ));
+        CompressedDLogProof { challenge, response: proof.response.clone() }
+    }
+
+    pub fn to_proof(&self, public_key: &Point<Secp256k1>, base_point: &Point<Secp256k1>) -> DLogProof {
+        DLogProof {
+            commitment: self.recompute_commitment(public_key, base_point),
+            response: self.response.clone(),
+        }
+    }
+
+    pub fn verify(
+        &self,
+        session_id: &str,
+        participant_id: i32,
+        public_key: Point<Secp256k1>,
+        base_point: Point<Secp256k1>,
+    ) -> bool {
+        let commitment = self.recompute_commitment(&public_key, &base_point);
+        if commitment.is_zero() {
+            return false;
+        }
+        let challenge = DLogProof::compute_challenge(
+            session_id,
+            participant_id,
+            vec![base_point, public_key, commitment],
+        );
+
+        challenge.as_ref() == Ok(&self.challenge)
+    }
+
+    fn recompute_commitment(&self, public_key: &Point<Secp256k1>, base_point: &Point<Secp256k1>) -> Point<Secp256k1> {
+        base_point * &self.response - public_key * &self.challenge
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compressed_proof() {
+        let session_id = "session_1";
+        let participant_id = 1;
+
+        let base_point = Point::generator();
+        let private_key = Scalar::random();
+        let public_key = base_point * private_key.clone();
+
+        let dlog_proof = DLogProof::generate_proof(
+            session_id,
+            participant_id,
+            private_key,
+            public_key.clone(),
+            base_point.into(),
+        );
+        let compressed = CompressedDLogProof::from_proof(
+            &dlog_proof,
+            session_id,
+            participant_id,
+            public_key.clone(),
+            base_point.into(),
+        );
+
+        assert!(dlog_proof.verify_proof(session_id, participant_id, public_key.clone(), base_point.into()).unwrap());
+        assert!(compressed.verify(session_id, participant_id, public_key.clone(), base_point.into()));
+        assert!(!compressed.verify("session_2", participant_id, public_key.clone(), base_point.into()));
+        assert!(!compressed.verify(
+            session_id,
+            participant_id,
+            public_key.clone() + base_point,
+            base_point.into()
+        ));
+
+        let restored = compressed.to_proof(&public_key, &base_point.into());
+        assert_eq!(restored, dlog_proof);
+        assert_eq!(
+            CompressedDLogProof::from_proof(&restored, session_id, participant_id, public_key, base_point.into()),
+            compressed
+        );
+    }
+}
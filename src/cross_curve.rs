@@ -0,0 +1,180 @@
+use curv::arithmetic::{BitManipulation, Modulo, Samplable};
+use curv::elliptic::curves::{Curve, Point, Scalar, Secp256k1};
+use curv::BigInt;
+
+use crate::hash::CROSS_CURVE_DOMAIN;
+use crate::{ChallengeHash, DLogError, DLogProof};
+
+// The nonce exceeds `challenge·secret` (< 2^512) by 128 bits, statistically hiding it.
+const NONCE_BITS: usize = 640;
+const RESPONSE_BITS: usize = NONCE_BITS + 1;
+
+/// Proof that `public_key_a = x·base_a` on secp256k1 and `public_key_b = x·base_b` on
+/// curve `E` for one secret `x`.
+///
+/// The two group orders differ, so the response is the integer `nonce + challenge·x`
+/// rather than a scalar of either curve, and each side reduces it into its own field.
+/// This shows both discrete logs are reductions of one integer; it does not show that
+/// integer is below both orders. Where a secret wrapping around on one curve matters,
+/// pair the proof with a range proof on `x`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CrossCurveEqProof<E: Curve> {
+    pub commitment_a: Point<Secp256k1>,
+    pub commitment_b: Point<E>,
+    pub response: BigInt,
+}
+
+impl<E: Curve> CrossCurveEqProof<E> {
+    /// Fails with [`DLogError::SecretOutOfRange`] unless `private_key` is also a
+    /// canonical scalar of `E`.
+    pub fn generate_proof(
+        session_id: &str,
+        participant_id: i32,
+        private_key: &Scalar<Secp256k1>,
+        base_a: &Point<Secp256k1>,
+        base_b: &Point<E>,
+    ) -> Result<CrossCurveEqProof<E>, DLogError> {
+        let secret = private_key.to_bigint();
+        if &secret >= Scalar::<E>::group_order() {
+            return Err(DLogError::SecretOutOfRange);
+        }
+        let public_key_a = base_a * private_key;
+        let public_key_b = base_b * reduce::<E>(&secret);
+
+        let nonce = BigInt::sample(NONCE_BITS);
+        let commitment_a = base_a * reduce::<Secp256k1>(&nonce);
+        let commitment_b = base_b * reduce::<E>(&nonce);
+        let challenge = cross_curve_challenge(
+            session_id,
+            participant_id,
+            [base_a, &public_key_a, &commitment_a],
+            [base_b, &public_key_b, &commitment_b],
+        )?;
+
+        Ok(CrossCurveEqProof { commitment_a, commitment_b, response: nonce + challenge * secret })
+    }
+
+    pub fn verify_proof(
+        &self,
+        session_id: &str,
+        participant_id: i32,
+        public_key_a: &Point<Secp256k1>,
+        public_key_b: &Point<E>,
+        base_a: &Point<Secp256k1>,
+        base_b: &Point<E>,
+    ) -> bool {
+        if self.response < BigInt::from(0)
+            || self.response.bit_length() > RESPONSE_BITS
+            || self.commitment_a.is_zero()
+            || self.commitment_b.is_zero()
+        {
+            return false;
+        }
+        let challenge = match cross_curve_challenge(
+            session_id,
+            participant_id,
+            [base_a, public_key_a, &self.commitment_a],
+            [base_b, public_key_b, &self.commitment_b],
+        ) {
+            Ok(challenge) => challenge,
+            Err(_) => return false,
+        };
+
+        let lhs_a = base_a * reduce::<Secp256k1>(&self.response);
+        let rhs_a = &self.commitment_a + public_key_a * reduce::<Secp256k1>(&challenge);
+        let lhs_b = base_b * reduce::<E>(&self.response);
+        let rhs_b = &self.commitment_b + public_key_b * reduce::<E>(&challenge);
+
+        lhs_a == rhs_a && lhs_b == rhs_b
+    }
+}
+
+// Reduce explicitly: curv's ed25519 `from_bigint` mishandles inputs wider than 512 bits.
+fn reduce<E: Curve>(n: &BigInt) -> Scalar<E> {
+    Scalar::from_bigint(&n.modulus(Scalar::<E>::group_order()))
+}
+
+fn cross_curve_challenge<E: Curve>(
+    session_id: &str,
+    participant_id: i32,
+    points_a: [&Point<Secp256k1>; 3],
+    points_b: [&Point<E>; 3],
+) -> Result<BigInt, DLogError> {
+    let session_len = (session_id.len() as u64).to_be_bytes();
+    let participant_id = participant_id.to_be_bytes();
+    let curve_name_len = (E::CURVE_NAME.len() as u64).to_be_bytes();
+    let encoded_a: Vec<_> = points_a.iter().map(|point| point.to_bytes(false)).collect();
+    let encoded_b: Vec<_> = points_b.iter().map(|point| point.to_bytes(false)).collect();
+
+    let mut parts: Vec<&[u8]> = vec![
+        CROSS_CURVE_DOMAIN,
+        &session_len,
+        session_id.as_bytes(),
+        &participant_id,
+        &curve_name_len,
+        E::CURVE_NAME.as_bytes(),
+    ];
+    for point in &encoded_a {
+        parts.push(point.as_ref());
+    }
+    for point in &encoded_b {
+        parts.push(point.as_ref());
+    }
+
+    Ok(DLogProof::challenge_from_digest(&ChallengeHash::DEFAULT.digest(parts))?.to_bigint())
+}
+
+#[cfg(test)]
+mod tests {
+    use curv::elliptic::curves::Ed25519;
+
+    use super::*;
+
+    fn secret_in_both() -> Scalar<Secp256k1> {
+        loop {
+            let private_key = Scalar::<Secp256k1>::random();
+            if &private_key.to_bigint() < Scalar::<Ed25519>::group_order() {
+                return private_key;
+            }
+        }
+    }
+
+    #[test]
+    fn test_cross_curve_eq_proof() {
+        let session_id = "session_1";
+        let participant_id = 1;
+
+        let base_a: Point<Secp256k1> = Point::generator().into();
+        let base_b: Point<Ed25519> = Point::generator().into();
+        let private_key = secret_in_both();
+        let public_key_a = &base_a * &private_key;
+        let public_key_b = &base_b * Scalar::<Ed25519>::from_bigint(&private_key.to_bigint());
+
+        let proof =
+            CrossCurveEqProof::generate_proof(session_id, participant_id, &private_key, &base_a, &base_b).unwrap();
+        assert!(proof.verify_proof(session_id, participant_id, &public_key_a, &public_key_b, &base_a, &base_b));
+        assert!(!proof.verify_proof("session_2", participant_id, &public_key_a, &public_key_b, &base_a, &base_b));
+    }
+
+    #[test]
+    fn test_cross_curve_eq_proof_fail_mismatched_secrets() {
+        let session_id = "session_1";
+        let participant_id = 1;
+
+        let base_a: Point<Secp256k1> = Point::generator().into();
+        let base_b: Point<Ed25519> = Point::generator().into();
+        let private_key = secret_in_both();
+        let public_key_a = &base_a * &private_key;
+        let other_key_b = &base_b * Scalar::<Ed25519>::random();
+
+        let proof =
+            CrossCurveEqProof::generate_proof(session_id, participant_id, &private_key, &base_a, &base_b).unwrap();
+        assert!(!proof.verify_proof(session_id, participant_id, &public_key_a, &other_key_b, &base_a, &base_b));
+
+        let too_large = Scalar::<Secp256k1>::from_bigint(Scalar::<Ed25519>::group_order());
+        assert_eq!(
+            CrossCurveEqProof::generate_proof(session_id, participant_id, &too_large, &base_a, &base_b),
+            Err(DLogError::SecretOutOfRange)
+        );
+    }
+}
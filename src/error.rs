@@ -0,0 +1,70 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DLogError {
+    /// Encoded proof has the wrong number of bytes.
+    InvalidLength { expected: usize, actual: usize },
+    /// Commitment bytes are not a valid secp256k1 point.
+    InvalidCommitment,
+    /// Response bytes are not a canonical secp256k1 scalar.
+    InvalidResponse,
+    /// Serialized point is not a 65-byte uncompressed encoding.
+    InvalidPointEncoding,
+    /// Challenge is zero, so the proof would say nothing about the private key.
+    ZeroChallenge,
+    /// Proof has a zero response, which no honest prover produces.
+    ZeroResponse,
+    /// Armored text is missing its header/footer or has a bad base64 body.
+    InvalidArmor,
+    /// Public key equals the base point, so the proven secret is trivially `1`.
+    TrivialKey,
+    /// Secret is not a canonical scalar on both curves of a cross-curve proof.
+    SecretOutOfRange,
+    /// Secret is zero, so it has no inverse.
+    NoInverse,
+    /// A conjunction of statements was built or checked with no statements.
+    EmptyStatement,
+    /// Session id is empty, leaving the challenge without a session to bind to.
+    EmptySessionId,
+    /// Base point is not the curve's standard generator.
+    BadGenerator,
+    /// Revealed public key does not hash to the commitment published for it.
+    KeyCommitmentMismatch,
+    /// A proof was already issued for this `(session_id, participant_id)` pair.
+    ReplayedContext,
+    /// Verification ran past its deadline.
+    Timeout,
+    /// Locked memory for a secret could not be allocated.
+    LockedMemory,
+    /// Background proof task panicked or was cancelled before completing.
+    TaskFailed,
+}
+
+impl fmt::Display for DLogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DLogError::InvalidLength { expected, actual } => {
+                write!(f, "invalid proof length: expected {} bytes, got {}", expected, actual)
+            }
+            DLogError::InvalidCommitment => write!(f, "invalid commitment point"),
+            DLogError::InvalidResponse => write!(f, "invalid response scalar"),
+            DLogError::InvalidPointEncoding => write!(f, "invalid uncompressed point encoding"),
+            DLogError::ZeroChallenge => write!(f, "challenge is zero"),
+            DLogError::ZeroResponse => write!(f, "proof response is zero"),
+            DLogError::InvalidArmor => write!(f, "invalid armored proof"),
+            DLogError::TrivialKey => write!(f, "public key equals the base point"),
+            DLogError::SecretOutOfRange => write!(f, "secret is out of range for one of the curves"),
+            DLogError::NoInverse => write!(f, "secret is zero and has no inverse"),
+            DLogError::EmptyStatement => write!(f, "no statements to prove"),
+            DLogError::EmptySessionId => write!(f, "session id is empty"),
+            DLogError::BadGenerator => write!(f, "base point is not the standard generator"),
+            DLogError::KeyCommitmentMismatch => write!(f, "revealed public key does not match its commitment"),
+            DLogError::ReplayedContext => write!(f, "proof already issued for this session and participant"),
+            DLogError::Timeout => write!(f, "verification exceeded its deadline"),
+            DLogError::LockedMemory => write!(f, "failed to allocate locked memory"),
+            DLogError::TaskFailed => write!(f, "proof task failed to complete"),
+        }
+    }
+}
+
+impl std::error::Error for DLogError {}
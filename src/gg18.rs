@@ -0,0 +1,105 @@
+//! DLog proofs in the format of curv-kzen's `sigma_dlog::DLogProof<Secp256k1, Sha256>`,
+//! which the gg18/gg20 MPC reference implementations use.
+//!
+//! That format differs from [`DLogProof::generate_proof`] in every detail of the
+//! challenge: points are hashed uncompressed in the order `[commitment, base_point,
+//! public_key]`, no session or participant id is hashed at all, and the digest is mapped
+//! to a scalar by rejection sampling over a big-endian `u32` counter suffix. The response
+//! is `nonce - challenge·private_key`. Because nothing binds these proofs to a session,
+//! callers have to bind them to their context by other means.
+
+use curv::cryptographic_primitives::hashing::DigestExt;
+use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+use sha2::Digest;
+
+use crate::DLogProof;
+
+impl DLogProof {
+    pub fn generate_gg18_compatible(
+        private_key: Scalar<Secp256k1>,
+        base_point: Point<Secp256k1>,
+    ) -> DLogProof {
+        let random_scalar = Scalar::random();
+        let commitment = &base_point * &random_scalar;
+        let public_key = &base_point * &private_key;
+        let challenge = gg18_challenge(&commitment, &base_point, &public_key);
+
+        let response = DLogProof::response_for(random_scalar, private_key, &-challenge);
+
+        DLogProof::new(commitment, response)
+    }
+
+    pub fn verify_gg18_compatible(
+        &self,
+        public_key: Point<Secp256k1>,
+        base_point: Point<Secp256k1>,
+    ) -> bool {
+        let challenge = gg18_challenge(&self.commitment, &base_point, &public_key);
+
+        base_point * &self.response + public_key * challenge == self.commitment
+    }
+}
+
+fn gg18_challenge(
+    commitment: &Point<Secp256k1>,
+    base_point: &Point<Secp256k1>,
+    public_key: &Point<Secp256k1>,
+) -> Scalar<Secp256k1> {
+    sha2::Sha256::new()
+        .chain_point(commitment)
+        .chain_point(base_point)
+        .chain_point(public_key)
+        .result_scalar()
+}
+
+#[cfg(test)]
+mod tests {
+    use curv::cryptographic_primitives::proofs::sigma_dlog;
+
+    use super::*;
+
+    // Captured from `sigma_dlog::DLogProof::<Secp256k1, Sha256>::prove`.
+    const FIXTURE_PUBLIC_KEY: &str = "039fe33c295d8c029e4addf14cf302c908217c9bba0e6a5ef519615ce4bb54dbd6";
+    const FIXTURE_COMMITMENT: &str = "0397262a31aa2f9d4ac202a1f6ebf493797c665d2ea5a3bf4f330bd218e1876399";
+    const FIXTURE_RESPONSE: &str = "9f05753332e3faa1fbeeddb729364886e446d4cb12cbe5ea70e9ca2231d50859";
+
+    fn point(hex_str: &str) -> Point<Secp256k1> {
+        Point::from_bytes(&hex::decode(hex_str).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_gg18_fixture_verifies() {
+        let base_point = Point::generator();
+        let public_key = point(FIXTURE_PUBLIC_KEY);
+        let dlog_proof = DLogProof {
+            commitment: point(FIXTURE_COMMITMENT),
+            response: Scalar::from_bytes(&hex::decode(FIXTURE_RESPONSE).unwrap()).unwrap(),
+        };
+
+        assert!(dlog_proof.verify_gg18_compatible(public_key.clone(), base_point.into()));
+        assert!(!dlog_proof.verify_gg18_compatible(public_key + base_point, base_point.into()));
+    }
+
+    #[test]
+    fn test_gg18_cross_verification() {
+        let base_point = Point::generator();
+        let private_key = Scalar::random();
+        let public_key = base_point * private_key.clone();
+
+        let reference = sigma_dlog::DLogProof::<Secp256k1, sha2::Sha256>::prove(&private_key);
+        let dlog_proof = DLogProof {
+            commitment: reference.pk_t_rand_commitment.clone(),
+            response: reference.challenge_response.clone(),
+        };
+        assert!(dlog_proof.verify_gg18_compatible(public_key.clone(), base_point.into()));
+
+        let ours = DLogProof::generate_gg18_compatible(private_key, base_point.into());
+        let reference = sigma_dlog::DLogProof::<Secp256k1, sha2::Sha256> {
+            pk: public_key,
+            pk_t_rand_commitment: ours.commitment,
+            challenge_response: ours.response,
+            hash_choice: Default::default(),
+        };
+        assert!(sigma_dlog::DLogProof::verify(&reference).is_ok());
+    }
+}
@@ -0,0 +1,66 @@
+use sha2::Digest;
+
+// Domain tags for challenge transcripts other than the plain `session_id || participant_id
+// || points` one; each such transcript starts with its tag. The leading 0xff can't occur in
+// UTF-8, so no `&str` session id starts the same way, and no tag is a prefix of another, so
+// the modes never accept each other's proofs.
+pub(crate) const ANONYMOUS_DOMAIN: &[u8] = b"\xffdlog_proof/anonymous";
+pub(crate) const AGGREGATE_DOMAIN: &[u8] = b"\xffdlog_proof/aggregate";
+pub(crate) const CROSS_CURVE_DOMAIN: &[u8] = b"\xffdlog_proof/cross_curve";
+pub(crate) const AAD_DOMAIN: &[u8] = b"\xffdlog_proof/aad";
+pub(crate) const LABELS_DOMAIN: &[u8] = b"\xffdlog_proof/labels";
+pub(crate) const CHAIN_DOMAIN: &[u8] = b"\xffdlog_proof/chain";
+pub(crate) const RETRY_DOMAIN: &[u8] = b"\xffdlog_proof/retry";
+pub(crate) const AND_DOMAIN: &[u8] = b"\xffdlog_proof/and";
+pub(crate) const CHAUM_PEDERSEN_DOMAIN: &[u8] = b"\xffdlog_proof/chaum_pedersen";
+
+/// Hash function challenges are derived with. A proof only verifies under the hash it
+/// was generated with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChallengeHash {
+    Sha256,
+    #[cfg(feature = "blake3")]
+    Blake3,
+    // Digests to the group order, which reduces to a zero challenge. No real hash input is
+    // known to do that, so tests use this to reach the `ZeroChallenge` paths.
+    #[cfg(test)]
+    ZeroDigest,
+}
+
+impl ChallengeHash {
+    /// Hash behind `generate_proof`/`verify_proof`: BLAKE3 with the `blake3` feature,
+    /// SHA-256 otherwise.
+    #[cfg(not(feature = "blake3"))]
+    pub const DEFAULT: ChallengeHash = ChallengeHash::Sha256;
+    #[cfg(feature = "blake3")]
+    pub const DEFAULT: ChallengeHash = ChallengeHash::Blake3;
+
+    pub(crate) fn digest<'a>(self, parts: impl IntoIterator<Item = &'a [u8]>) -> [u8; 32] {
+        match self {
+            ChallengeHash::Sha256 => {
+                let mut hasher = sha2::Sha256::new();
+                for part in parts {
+                    hasher.update(part);
+                }
+                hasher.finalize().into()
+            }
+            #[cfg(feature = "blake3")]
+            ChallengeHash::Blake3 => {
+                let mut hasher = blake3::Hasher::new();
+                for part in parts {
+                    hasher.update(part);
+                }
+                *hasher.finalize().as_bytes()
+            }
+            #[cfg(test)]
+            ChallengeHash::ZeroDigest => {
+                use curv::arithmetic::Converter;
+                use curv::elliptic::curves::{Scalar, Secp256k1};
+
+                let mut digest = [0u8; 32];
+                digest.copy_from_slice(&Scalar::<Secp256k1>::group_order().to_bytes());
+                digest
+            }
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+
+use crate::{ChallengeHash, DLogError, DLogProof};
+
+/// Byte order of the participant id in the challenge input. Ids whose bytes read the
+/// same both ways, such as `0` or `-1`, give the same challenge under either.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ParticipantIdEncoding {
+    /// What [`DLogProof::generate_proof`] and [`DLogProof::verify_proof`] use.
+    #[default]
+    BigEndian,
+    /// For implementations that hash `participant_id.to_le_bytes()`.
+    LittleEndian,
+}
+
+impl ParticipantIdEncoding {
+    fn encode(self, participant_id: i32) -> [u8; 4] {
+        match self {
+            ParticipantIdEncoding::BigEndian => participant_id.to_be_bytes(),
+            ParticipantIdEncoding::LittleEndian => participant_id.to_le_bytes(),
+        }
+    }
+}
+
+impl DLogProof {
+    /// [`DLogProof::compute_challenge_with`] under [`ChallengeHash::DEFAULT`] with the
+    /// participant id in the given byte order.
+    pub fn compute_challenge_with_encoding(
+        encoding: ParticipantIdEncoding,
+        session_id: &str,
+        participant_id: i32,
+        points: &[Point<Secp256k1>],
+    ) -> Result<Scalar<Secp256k1>, DLogError> {
+        let participant_id = encoding.encode(participant_id);
+        let points: Vec<_> = points.iter().map(|point| point.to_bytes(false)).collect();
+
+        let mut parts: Vec<&[u8]> = vec![session_id.as_bytes(), &participant_id];
+        parts.extend(points.iter().map(|point| point.as_ref()));
+        DLogProof::challenge_from_digest(&ChallengeHash::DEFAULT.digest(parts))
+    }
+
+    pub fn generate_proof_with_encoding(
+        encoding: ParticipantIdEncoding,
+        session_id: &str,
+        participant_id: i32,
+        private_key: Scalar<Secp256k1>,
+        public_key: Point<Secp256k1>,
+        base_point: Point<Secp256k1>,
+    ) -> DLogProof {
+        let random_scalar = Scalar::random();
+        let commitment = &base_point * &random_scalar;
+        let challenge = DLogProof::expect_challenge(DLogProof::compute_challenge_with_encoding(
+            encoding,
+            session_id,
+            participant_id,
+            &[base_point, public_key, commitment.clone()],
+        ));
+
+        DLogProof::new(commitment, DLogProof::response_for(random_scalar, private_key, &challenge))
+    }
+
+    pub fn verify_proof_with_encoding(
+        &self,
+        encoding: ParticipantIdEncoding,
+        session_id: &str,
+        participant_id: i32,
+        public_key: Point<Secp256k1>,
+        base_point: Point<Secp256k1>,
+    ) -> Result<bool, DLogError> {
+        let challenge = DLogProof::compute_challenge_with_encoding(
+            encoding,
+            session_id,
+            participant_id,
+            &[base_point.clone(), public_key.clone(), self.commitment.clone()],
+        )?;
+        self.verify_proof_with_challenge(&challenge, public_key, base_point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_participant_id_endianness() {
+        let session_id = "session_1";
+        let participant_id = 1;
+
+        let base_point: Point<Secp256k1> = Point::generator().into();
+        let private_key = Scalar::random();
+        let public_key = &base_point * &private_key;
+        let verify = |proof: &DLogProof, encoding| {
+            proof.verify_proof_with_encoding(
+                encoding,
+                session_id,
+                participant_id,
+                public_key.clone(),
+                base_point.clone(),
+            )
+        };
+
+        let little_endian = DLogProof::generate_proof_with_encoding(
+            ParticipantIdEncoding::LittleEndian,
+            session_id,
+            participant_id,
+            private_key.clone(),
+            public_key.clone(),
+            base_point.clone(),
+        );
+        assert_eq!(verify(&little_endian, ParticipantIdEncoding::LittleEndian), Ok(true));
+        assert_eq!(verify(&little_endian, ParticipantIdEncoding::BigEndian), Ok(false));
+        assert_eq!(
+            little_endian.verify_proof(session_id, participant_id, public_key.clone(), base_point.clone()),
+            Ok(false)
+        );
+
+        let big_endian = DLogProof::generate_proof(
+            session_id,
+            participant_id,
+            private_key,
+            public_key.clone(),
+            base_point.clone(),
+        );
+        assert_eq!(verify(&big_endian, ParticipantIdEncoding::default()), Ok(true));
+        assert_eq!(verify(&big_endian, ParticipantIdEncoding::LittleEndian), Ok(false));
+    }
+}
@@ -0,0 +1,89 @@
+use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+
+use crate::{DLogError, DLogProof};
+
+/// Proofs of knowledge of `x` for `public_key = x·base_point` and of `x⁻¹` for
+/// `inverse_key = x⁻¹·base_point`, made in one session.
+///
+/// What the verifier can check: each proof on its own, and that neither key is the
+/// identity. It cannot check `x·inverse_key == base_point` without `x`, so the two
+/// proofs do not show the secrets are inverses of each other. That link needs a
+/// discrete-log-equality proof that `log_base_point(public_key) == log_inverse_key(base_point)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InverseDLogProof {
+    pub proof: DLogProof,
+    pub inverse_proof: DLogProof,
+}
+
+impl InverseDLogProof {
+    /// Fails with [`DLogError::NoInverse`] if `private_key` is zero.
+    pub fn generate_proof(
+        session_id: &str,
+        participant_id: i32,
+        private_key: Scalar<Secp256k1>,
+        base_point: Point<Secp256k1>,
+    ) -> Result<InverseDLogProof, DLogError> {
+        let inverse = private_key.invert().ok_or(DLogError::NoInverse)?;
+        let public_key = &base_point * &private_key;
+        let inverse_key = &base_point * &inverse;
+
+        Ok(InverseDLogProof {
+            proof: DLogProof::generate_proof(session_id, participant_id, private_key, public_key, base_point.clone()),
+            inverse_proof: DLogProof::generate_proof(session_id, participant_id, inverse, inverse_key, base_point),
+        })
+    }
+
+    pub fn verify_proof(
+        &self,
+        session_id: &str,
+        participant_id: i32,
+        public_key: Point<Secp256k1>,
+        inverse_key: Point<Secp256k1>,
+        base_point: Point<Secp256k1>,
+    ) -> Result<bool, DLogError> {
+        if public_key.is_zero() || inverse_key.is_zero() {
+            return Ok(false);
+        }
+        Ok(self.proof.verify_proof(session_id, participant_id, public_key, base_point.clone())?
+            && self.inverse_proof.verify_proof(session_id, participant_id, inverse_key, base_point)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inverse_proof() {
+        let session_id = "session_1";
+        let participant_id = 1;
+
+        let base_point: Point<Secp256k1> = Point::generator().into();
+        let private_key = Scalar::random();
+        let public_key = &base_point * &private_key;
+        let inverse_key = &base_point * private_key.invert().unwrap();
+        assert_eq!(&inverse_key * &private_key, base_point);
+
+        let proofs =
+            InverseDLogProof::generate_proof(session_id, participant_id, private_key, base_point.clone()).unwrap();
+        let verify = |public_key: &Point<Secp256k1>, inverse_key: &Point<Secp256k1>| {
+            proofs.verify_proof(session_id, participant_id, public_key.clone(), inverse_key.clone(), base_point.clone())
+        };
+        assert_eq!(verify(&public_key, &inverse_key), Ok(true));
+        assert_eq!(verify(&inverse_key, &public_key), Ok(false));
+        assert_eq!(verify(&public_key, &Point::zero()), Ok(false));
+        assert_eq!(
+            proofs.verify_proof("session_2", participant_id, public_key, inverse_key, base_point),
+            Ok(false)
+        );
+    }
+
+    #[test]
+    fn test_inverse_proof_rejects_zero() {
+        let base_point: Point<Secp256k1> = Point::generator().into();
+        assert_eq!(
+            InverseDLogProof::generate_proof("session_1", 1, Scalar::zero(), base_point),
+            Err(DLogError::NoInverse)
+        );
+    }
+}
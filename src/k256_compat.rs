@@ -0,0 +1,103 @@
+use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+use k256::elliptic_curve::group::GroupEncoding;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use k256::elliptic_curve::PrimeField;
+
+use crate::DLogProof;
+
+/// A [`DLogProof`] expressed in RustCrypto `k256` types.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct K256DLogProof {
+    pub commitment: k256::AffinePoint,
+    pub response: k256::Scalar,
+}
+
+impl From<&DLogProof> for K256DLogProof {
+    fn from(proof: &DLogProof) -> Self {
+        K256DLogProof {
+            commitment: point_to_k256(&proof.commitment),
+            response: scalar_to_k256(&proof.response),
+        }
+    }
+}
+
+impl From<K256DLogProof> for DLogProof {
+    fn from(proof: K256DLogProof) -> Self {
+        DLogProof::new(point_from_k256(&proof.commitment), scalar_from_k256(&proof.response))
+    }
+}
+
+pub fn point_to_k256(point: &Point<Secp256k1>) -> k256::AffinePoint {
+    if point.is_zero() {
+        return k256::AffinePoint::IDENTITY;
+    }
+    let compressed = k256::CompressedPoint::clone_from_slice(point.to_bytes(true).as_ref());
+    Option::from(k256::AffinePoint::from_bytes(&compressed)).expect("curv points are valid secp256k1 points")
+}
+
+pub fn point_from_k256(point: &k256::AffinePoint) -> Point<Secp256k1> {
+    let encoded = point.to_encoded_point(true);
+    if encoded.is_identity() {
+        return Point::zero();
+    }
+    Point::from_bytes(encoded.as_bytes()).expect("k256 points are valid secp256k1 points")
+}
+
+pub fn scalar_to_k256(scalar: &Scalar<Secp256k1>) -> k256::Scalar {
+    let repr = k256::FieldBytes::clone_from_slice(scalar.to_bytes().as_ref());
+    Option::from(k256::Scalar::from_repr(repr)).expect("curv scalars are reduced")
+}
+
+pub fn scalar_from_k256(scalar: &k256::Scalar) -> Scalar<Secp256k1> {
+    Scalar::from_bytes(&scalar.to_bytes()).expect("k256 scalars are reduced")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_k256_round_trip() {
+        let base_point = Point::generator();
+        let scalar = Scalar::<Secp256k1>::random();
+        let point = base_point * scalar.clone();
+
+        let k256_point = point_to_k256(&point);
+        assert_eq!(k256_point.to_encoded_point(true).as_bytes(), point.to_bytes(true).as_ref());
+        assert_eq!(k256_point.to_encoded_point(false).as_bytes(), point.to_bytes(false).as_ref());
+        assert_eq!(point_from_k256(&k256_point), point);
+
+        let k256_scalar = scalar_to_k256(&scalar);
+        assert_eq!(k256_scalar.to_bytes().as_slice(), scalar.to_bytes().as_ref());
+        assert_eq!(scalar_from_k256(&k256_scalar), scalar);
+
+        // Same point from the k256 side of the arithmetic.
+        assert_eq!(k256::AffinePoint::from(k256::ProjectivePoint::GENERATOR * k256_scalar), k256_point);
+
+        assert_eq!(point_to_k256(&Point::zero()), k256::AffinePoint::IDENTITY);
+        assert!(point_from_k256(&k256::AffinePoint::IDENTITY).is_zero());
+        assert!(scalar_from_k256(&k256::Scalar::ZERO).is_zero());
+    }
+
+    #[test]
+    fn test_k256_proof_conversion() {
+        let session_id = "session_1";
+        let participant_id = 1;
+
+        let base_point = Point::generator();
+        let private_key = Scalar::random();
+        let public_key = base_point * private_key.clone();
+        let dlog_proof = DLogProof::generate_proof(
+            session_id,
+            participant_id,
+            private_key,
+            public_key.clone(),
+            base_point.into(),
+        );
+
+        let k256_proof = K256DLogProof::from(&dlog_proof);
+        let restored = DLogProof::from(k256_proof);
+        assert_eq!(restored, dlog_proof);
+        assert!(restored.verify_proof(session_id, participant_id, public_key, base_point.into()).unwrap());
+    }
+}
@@ -0,0 +1,168 @@
+use std::collections::BTreeMap;
+
+use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+
+use crate::hash::LABELS_DOMAIN;
+use crate::{ChallengeHash, DLogError, DLogProof};
+
+impl DLogProof {
+    /// [`DLogProof::compute_challenge`] with named labels (e.g. `"purpose" => "withdrawal"`)
+    /// folded in after the points, under its own domain tag and a length-prefixed session
+    /// id. Labels are hashed in key order as a count followed by length-prefixed keys and
+    /// values, so the challenge depends only on the map's contents.
+    pub fn compute_challenge_with_labels(
+        session_id: &str,
+        participant_id: i32,
+        points: &[Point<Secp256k1>],
+        labels: &BTreeMap<String, Vec<u8>>,
+    ) -> Result<Scalar<Secp256k1>, DLogError> {
+        let session_len = (session_id.len() as u64).to_be_bytes();
+        let points: Vec<_> = points.iter().map(|point| point.to_bytes(false)).collect();
+        let count = (labels.len() as u64).to_be_bytes();
+        let lengths: Vec<_> = labels
+            .iter()
+            .map(|(key, value)| ((key.len() as u64).to_be_bytes(), (value.len() as u64).to_be_bytes()))
+            .collect();
+
+        let mut parts: Vec<&[u8]> = vec![&session_len, session_id.as_bytes()];
+        parts.extend(points.iter().map(|point| point.as_ref()));
+        parts.push(&count);
+        for ((key, value), (key_len, value_len)) in labels.iter().zip(&lengths) {
+            parts.extend([&key_len[..], key.as_bytes(), &value_len[..], value]);
+        }
+        DLogProof::hash_challenge(ChallengeHash::DEFAULT, LABELS_DOMAIN, participant_id, parts.into_iter())
+    }
+
+    pub fn generate_proof_with_labels(
+        session_id: &str,
+        participant_id: i32,
+        private_key: Scalar<Secp256k1>,
+        public_key: Point<Secp256k1>,
+        base_point: Point<Secp256k1>,
+        labels: &BTreeMap<String, Vec<u8>>,
+    ) -> DLogProof {
+        let random_scalar = Scalar::random();
+        let commitment = &base_point * &random_scalar;
+        let challenge = DLogProof::expect_challenge(DLogProof::compute_challenge_with_labels(
+            session_id,
+            participant_id,
+            &[base_point, public_key, commitment.clone()],
+            labels,
+        ));
+
+        DLogProof::new(commitment, DLogProof::response_for(random_scalar, private_key, &challenge))
+    }
+
+    /// Verifies a proof from [`DLogProof::generate_proof_with_labels`] against the labels
+    /// the verifier expects; any added, removed or changed label fails verification.
+    pub fn verify_proof_with_labels(
+        &self,
+        session_id: &str,
+        participant_id: i32,
+        public_key: Point<Secp256k1>,
+        base_point: Point<Secp256k1>,
+        labels: &BTreeMap<String, Vec<u8>>,
+    ) -> Result<bool, DLogError> {
+        let challenge = DLogProof::compute_challenge_with_labels(
+            session_id,
+            participant_id,
+            &[base_point.clone(), public_key.clone(), self.commitment.clone()],
+            labels,
+        )?;
+        self.verify_proof_with_challenge(&challenge, public_key, base_point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AadDLogProof;
+
+    fn labels(entries: &[(&str, &[u8])]) -> BTreeMap<String, Vec<u8>> {
+        entries.iter().map(|(key, value)| (key.to_string(), value.to_vec())).collect()
+    }
+
+    #[test]
+    fn test_label_tampering() {
+        let session_id = "session_1";
+        let participant_id = 1;
+
+        let base_point: Point<Secp256k1> = Point::generator().into();
+        let private_key = Scalar::random();
+        let public_key = &base_point * &private_key;
+        let signed = labels(&[("purpose", b"withdrawal"), ("amount", b"100")]);
+
+        let dlog_proof = DLogProof::generate_proof_with_labels(
+            session_id,
+            participant_id,
+            private_key,
+            public_key.clone(),
+            base_point.clone(),
+            &signed,
+        );
+        let verify = |labels: &BTreeMap<String, Vec<u8>>| {
+            dlog_proof.verify_proof_with_labels(
+                session_id,
+                participant_id,
+                public_key.clone(),
+                base_point.clone(),
+                labels,
+            )
+        };
+        assert_eq!(verify(&signed), Ok(true));
+
+        assert_eq!(verify(&labels(&[("purpose", b"deposit"), ("amount", b"100")])), Ok(false));
+        assert_eq!(verify(&labels(&[("purpose", b"withdrawal")])), Ok(false));
+        assert_eq!(verify(&labels(&[("purpose", b"withdrawal"), ("amount", b"100"), ("memo", b"")])), Ok(false));
+        // Moving bytes across the key/value boundary changes the length prefixes.
+        assert_eq!(verify(&labels(&[("purpose", b"withdrawal"), ("amount1", b"00")])), Ok(false));
+        assert_eq!(verify(&BTreeMap::new()), Ok(false));
+        assert!(!dlog_proof.verify_proof(session_id, participant_id, public_key, base_point).unwrap());
+    }
+
+    #[test]
+    fn test_labels_proof_rejected_in_aad_mode() {
+        let base_point: Point<Secp256k1> = Point::generator().into();
+        let private_key = Scalar::random();
+        let public_key = &base_point * &private_key;
+
+        // An empty label map and empty associated data both end in a zero length.
+        let dlog_proof = DLogProof::generate_proof_with_labels(
+            "session_1",
+            1,
+            private_key.clone(),
+            public_key.clone(),
+            base_point.clone(),
+            &BTreeMap::new(),
+        );
+        let verify = |proof: &DLogProof| {
+            proof.verify_proof_with_labels("session_1", 1, public_key.clone(), base_point.clone(), &BTreeMap::new())
+        };
+        assert_eq!(verify(&dlog_proof), Ok(true));
+
+        let as_aad = AadDLogProof { proof: dlog_proof, aad: vec![] };
+        assert_eq!(as_aad.verify_proof("session_1", 1, public_key.clone(), base_point.clone()), Ok(false));
+
+        let aad_proof =
+            AadDLogProof::generate_proof("session_1", 1, private_key, public_key.clone(), base_point.clone(), &[]);
+        assert_eq!(aad_proof.verify_proof("session_1", 1, public_key.clone(), base_point.clone()), Ok(true));
+        assert_eq!(verify(&aad_proof.proof), Ok(false));
+    }
+
+    #[test]
+    fn test_label_order_independence() {
+        let points = [Point::generator().to_point(), Point::base_point2().clone()];
+
+        let mut first = BTreeMap::new();
+        first.insert("purpose".to_string(), b"withdrawal".to_vec());
+        first.insert("amount".to_string(), b"100".to_vec());
+        let mut second = BTreeMap::new();
+        second.insert("amount".to_string(), b"100".to_vec());
+        second.insert("purpose".to_string(), b"withdrawal".to_vec());
+
+        assert_eq!(
+            DLogProof::compute_challenge_with_labels("session_1", 1, &points, &first),
+            DLogProof::compute_challenge_with_labels("session_1", 1, &points, &second)
+        );
+    }
+}
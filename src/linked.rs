@@ -0,0 +1,155 @@
+use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+
+use crate::DLogProof;
+
+/// Proof that participant ids `a` and `b` in one session are backed by the same secret.
+///
+/// A single nonce and response answer two challenges, one per participant id, so the
+/// proof only verifies for the pair as a whole and cannot be split into a standalone
+/// `DLogProof` for either id.
+///
+/// Privacy: the proof is a transferable, publicly verifiable statement that both ids
+/// belong to one key holder. Anyone who sees it can link the two ids, and the link cannot
+/// be denied later. Only generate it when that linkage is meant to be public.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LinkedDLogProof {
+    pub commitment: Point<Secp256k1>,
+    pub response: Scalar<Secp256k1>,
+}
+
+impl LinkedDLogProof {
+    pub fn generate_proof(
+        session_id: &str,
+        participant_a: i32,
+        participant_b: i32,
+        private_key: Scalar<Secp256k1>,
+        public_key: Point<Secp256k1>,
+        base_point: Point<Secp256k1>,
+    ) -> LinkedDLogProof {
+        let random_scalar = Scalar::random();
+        let commitment = base_point.clone() * random_scalar.clone();
+        let points = vec![base_point, public_key, commitment.clone()];
+        let challenge_a =
+            DLogProof::expect_challenge(DLogProof::compute_challenge(session_id, participant_a, points.clone()));
+        let challenge_b = DLogProof::expect_challenge(DLogProof::compute_challenge(session_id, participant_b, points));
+
+        let response = DLogProof::response_for(random_scalar, private_key, &(challenge_a + challenge_b));
+
+        LinkedDLogProof { commitment, response }
+    }
+
+    /// Verifies the link given the public keys registered for `a` and `b`.
+    ///
+    /// Over one base point a shared secret means a shared public key, so differing keys
+    /// are rejected before any curve arithmetic.
+    pub fn verify_proof(
+        &self,
+        session_id: &str,
+        participant_a: i32,
+        participant_b: i32,
+        public_key_a: Point<Secp256k1>,
+        public_key_b: Point<Secp256k1>,
+        base_point: Point<Secp256k1>,
+    ) -> bool {
+        if public_key_a != public_key_b || self.commitment.is_zero() {
+            return false;
+        }
+        let points = vec![base_point.clone(), public_key_a.clone(), self.commitment.clone()];
+        let challenges = (
+            DLogProof::compute_challenge(session_id, participant_a, points.clone()),
+            DLogProof::compute_challenge(session_id, participant_b, points),
+        );
+        let (challenge_a, challenge_b) = match challenges {
+            (Ok(challenge_a), Ok(challenge_b)) => (challenge_a, challenge_b),
+            _ => return false,
+        };
+
+        let lhs = base_point * self.response.clone();
+        let rhs = self.commitment.clone() + (challenge_a + challenge_b) * public_key_a;
+
+        lhs == rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linked_proof() {
+        let session_id = "session_1";
+        let (participant_a, participant_b) = (1, 2);
+
+        let base_point = Point::generator();
+        let private_key = Scalar::random();
+        let public_key = base_point * private_key.clone();
+
+        let linked = LinkedDLogProof::generate_proof(
+            session_id,
+            participant_a,
+            participant_b,
+            private_key,
+            public_key.clone(),
+            base_point.into(),
+        );
+        assert!(linked.verify_proof(
+            session_id,
+            participant_a,
+            participant_b,
+            public_key.clone(),
+            public_key.clone(),
+            base_point.into(),
+        ));
+        assert!(!linked.verify_proof(
+            session_id,
+            participant_a,
+            3,
+            public_key.clone(),
+            public_key.clone(),
+            base_point.into(),
+        ));
+
+        // Not usable as a plain proof for either participant on its own.
+        let plain = DLogProof {
+            commitment: linked.commitment.clone(),
+            response: linked.response.clone(),
+        };
+        assert!(!plain.verify_proof(session_id, participant_a, public_key, base_point.into()).unwrap());
+    }
+
+    #[test]
+    fn test_linked_proof_fail_different_keys() {
+        let session_id = "session_1";
+        let (participant_a, participant_b) = (1, 2);
+
+        let base_point = Point::generator();
+        let private_key_a = Scalar::random();
+        let public_key_a = base_point * private_key_a.clone();
+        let public_key_b = base_point * Scalar::random();
+
+        let linked = LinkedDLogProof::generate_proof(
+            session_id,
+            participant_a,
+            participant_b,
+            private_key_a,
+            public_key_a.clone(),
+            base_point.into(),
+        );
+        assert!(!linked.verify_proof(
+            session_id,
+            participant_a,
+            participant_b,
+            public_key_a.clone(),
+            public_key_b.clone(),
+            base_point.into(),
+        ));
+        assert!(!linked.verify_proof(
+            session_id,
+            participant_a,
+            participant_b,
+            public_key_b.clone(),
+            public_key_b,
+            base_point.into(),
+        ));
+    }
+}
@@ -0,0 +1,99 @@
+use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+
+use crate::DLogProof;
+
+/// Proof of knowledge of an opening `(v, r)` of a Pedersen commitment `C = v·g + r·h`,
+/// revealing neither value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PedersenOpeningProof {
+    pub commitment: Point<Secp256k1>,
+    pub response_v: Scalar<Secp256k1>,
+    pub response_r: Scalar<Secp256k1>,
+}
+
+impl PedersenOpeningProof {
+    pub fn generate(
+        session_id: &str,
+        participant_id: i32,
+        v: Scalar<Secp256k1>,
+        r: Scalar<Secp256k1>,
+        g: Point<Secp256k1>,
+        h: Point<Secp256k1>,
+    ) -> PedersenOpeningProof {
+        let pedersen_commitment = &g * &v + &h * &r;
+        let (nonce_v, nonce_r) = (Scalar::random(), Scalar::random());
+        let commitment = &g * &nonce_v + &h * &nonce_r;
+        let challenge = DLogProof::expect_challenge(DLogProof::compute_challenge(
+            session_id,
+            participant_id,
+            vec![g, h, pedersen_commitment, commitment.clone()],
+        ));
+
+        PedersenOpeningProof {
+            commitment,
+            response_v: nonce_v + &challenge * v,
+            response_r: nonce_r + challenge * r,
+        }
+    }
+
+    /// Checks `proof` opens `pedersen_commitment` with respect to `g` and `h`.
+    pub fn verify(
+        session_id: &str,
+        participant_id: i32,
+        pedersen_commitment: Point<Secp256k1>,
+        proof: &PedersenOpeningProof,
+        g: Point<Secp256k1>,
+        h: Point<Secp256k1>,
+    ) -> bool {
+        if proof.commitment.is_zero() {
+            return false;
+        }
+        let challenge = match DLogProof::compute_challenge(
+            session_id,
+            participant_id,
+            vec![g.clone(), h.clone(), pedersen_commitment.clone(), proof.commitment.clone()],
+        ) {
+            Ok(challenge) => challenge,
+            Err(_) => return false,
+        };
+        let lhs = g * &proof.response_v + h * &proof.response_r;
+        let rhs = &proof.commitment + pedersen_commitment * challenge;
+
+        lhs == rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pedersen_opening_proof() {
+        let g: Point<Secp256k1> = Point::generator().into();
+        let h = Point::base_point2().clone();
+        let (v, r) = (Scalar::random(), Scalar::random());
+        let pedersen_commitment = &g * &v + &h * &r;
+
+        let proof = PedersenOpeningProof::generate("session_1", 1, v, r, g.clone(), h.clone());
+        assert!(PedersenOpeningProof::verify(
+            "session_1",
+            1,
+            pedersen_commitment.clone(),
+            &proof,
+            g.clone(),
+            h.clone()
+        ));
+        assert!(!PedersenOpeningProof::verify("session_2", 1, pedersen_commitment, &proof, g, h));
+    }
+
+    #[test]
+    fn test_pedersen_opening_proof_fail_wrong_commitment() {
+        let g: Point<Secp256k1> = Point::generator().into();
+        let h = Point::base_point2().clone();
+        let (v, r) = (Scalar::random(), Scalar::random());
+        let claimed = &g * Scalar::random() + &h * &r;
+
+        let proof = PedersenOpeningProof::generate("session_1", 1, v, r, g.clone(), h.clone());
+        assert!(!PedersenOpeningProof::verify("session_1", 1, claimed, &proof, g, h));
+    }
+}
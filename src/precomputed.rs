@@ -0,0 +1,137 @@
+use curv::elliptic::curves::{Point, Secp256k1};
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use k256::elliptic_curve::PrimeField;
+
+use crate::{point_to_k256, scalar_to_k256, DLogError, DLogProof};
+
+// 4-bit windows: 64 rows of 16 multiples each, about 100 KB per table.
+const WINDOW_ROWS: usize = 64;
+
+/// Verifies [`DLogProof`]s for one fixed public key and base point, e.g. a node that
+/// proves repeatedly.
+///
+/// Construction builds fixed-base tables for both points (a few thousand point
+/// additions), after which each verification replaces the two full scalar
+/// multiplications with 64 table additions each. Worth it once a key is verified more
+/// than a handful of times; `cargo bench --features k256` shows the difference. Lookups
+/// depend on the challenge and response, which are public, so the tables are not for
+/// secret scalars.
+pub struct PublicKeyVerifier {
+    // Uncompressed encodings, as the challenge hashes them.
+    public_key: Vec<u8>,
+    base_point: Vec<u8>,
+    public_key_table: FixedBaseTable,
+    base_point_table: FixedBaseTable,
+}
+
+impl PublicKeyVerifier {
+    pub fn new(public_key: Point<Secp256k1>, base_point: Point<Secp256k1>) -> PublicKeyVerifier {
+        PublicKeyVerifier {
+            public_key: public_key.to_bytes(false).to_vec(),
+            base_point: base_point.to_bytes(false).to_vec(),
+            public_key_table: FixedBaseTable::new(&public_key),
+            base_point_table: FixedBaseTable::new(&base_point),
+        }
+    }
+
+    /// Same result as [`DLogProof::verify_proof`] for this verifier's key and base point.
+    pub fn verify_proof(&self, proof: &DLogProof, session_id: &str, participant_id: i32) -> Result<bool, DLogError> {
+        if proof.response.is_zero() {
+            return Err(DLogError::ZeroResponse);
+        }
+        if proof.commitment.is_zero() {
+            return Ok(false);
+        }
+        let commitment = proof.commitment.to_bytes(false);
+        let challenge = DLogProof::compute_challenge_from_bytes(
+            session_id,
+            participant_id,
+            &[&self.base_point, &self.public_key, commitment.as_ref()],
+        )?;
+
+        // response·base_point - challenge·public_key must reproduce the commitment.
+        let recomputed = self.base_point_table.mul(&scalar_to_k256(&proof.response))
+            - self.public_key_table.mul(&scalar_to_k256(&challenge));
+        Ok(recomputed.to_affine().to_encoded_point(true).as_bytes() == proof.commitment.to_bytes(true).as_ref())
+    }
+}
+
+// Row `i` holds `j·16^i·point` for `j` in `0..16`.
+struct FixedBaseTable(Vec<[k256::ProjectivePoint; 16]>);
+
+impl FixedBaseTable {
+    fn new(point: &Point<Secp256k1>) -> FixedBaseTable {
+        let mut rows = Vec::with_capacity(WINDOW_ROWS);
+        let mut base = k256::ProjectivePoint::from(point_to_k256(point));
+        for _ in 0..WINDOW_ROWS {
+            let mut row = [k256::ProjectivePoint::IDENTITY; 16];
+            for j in 1..16 {
+                row[j] = row[j - 1] + base;
+            }
+            base = row[15] + base;
+            rows.push(row);
+        }
+        FixedBaseTable(rows)
+    }
+
+    fn mul(&self, scalar: &k256::Scalar) -> k256::ProjectivePoint {
+        let bytes = scalar.to_repr();
+        let nibbles = bytes.iter().rev().flat_map(|byte| [byte & 0x0f, byte >> 4]);
+        self.0
+            .iter()
+            .zip(nibbles)
+            .fold(k256::ProjectivePoint::IDENTITY, |acc, (row, nibble)| acc + row[nibble as usize])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use curv::elliptic::curves::Scalar;
+
+    use super::*;
+
+    #[test]
+    fn test_fixed_base_table() {
+        let point = Point::<Secp256k1>::generator() * Scalar::random();
+        let table = FixedBaseTable::new(&point);
+        for scalar in [Scalar::random(), Scalar::zero(), Scalar::from(1), -Scalar::from(1)] {
+            let expected = point_to_k256(&(&point * &scalar));
+            assert_eq!(k256::AffinePoint::from(table.mul(&scalar_to_k256(&scalar))), expected);
+        }
+    }
+
+    #[test]
+    fn test_public_key_verifier_matches_verify_proof() {
+        let session_id = "session_1";
+        let participant_id = 1;
+
+        let base_point: Point<Secp256k1> = Point::generator().into();
+        let private_key = Scalar::random();
+        let public_key = &base_point * &private_key;
+        let verifier = PublicKeyVerifier::new(public_key.clone(), base_point.clone());
+
+        let proof = |private_key: &Scalar<Secp256k1>| {
+            DLogProof::generate_proof(
+                session_id,
+                participant_id,
+                private_key.clone(),
+                public_key.clone(),
+                base_point.clone(),
+            )
+        };
+        let cases = [
+            (proof(&private_key), session_id),
+            (proof(&private_key), "session_2"),
+            (proof(&Scalar::random()), session_id),
+            (DLogProof { commitment: Point::zero(), ..proof(&private_key) }, session_id),
+            (DLogProof::placeholder(), session_id),
+        ];
+        for (dlog_proof, session_id) in cases {
+            assert_eq!(
+                verifier.verify_proof(&dlog_proof, session_id, participant_id),
+                dlog_proof.verify_proof(session_id, participant_id, public_key.clone(), base_point.clone())
+            );
+        }
+        assert_eq!(verifier.verify_proof(&proof(&private_key), session_id, participant_id), Ok(true));
+    }
+}
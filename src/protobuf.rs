@@ -0,0 +1,84 @@
+use curv::elliptic::curves::{Point, Scalar};
+
+use crate::{DLogError, DLogProof};
+
+/// Protobuf message for a [`DLogProof`]:
+///
+/// ```text
+/// message DLogProof {
+///   bytes commitment_bytes = 1; // SEC1 point, compressed or uncompressed
+///   bytes response_bytes = 2;   // 32-byte big-endian scalar
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct DLogProofProto {
+    #[prost(bytes = "vec", tag = "1")]
+    pub commitment_bytes: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub response_bytes: Vec<u8>,
+}
+
+impl DLogProof {
+    /// Protobuf form, with the commitment compressed as in [`DLogProof::to_bytes`].
+    pub fn to_proto(&self) -> DLogProofProto {
+        DLogProofProto {
+            commitment_bytes: self.commitment.to_bytes(true).to_vec(),
+            response_bytes: self.response.to_bytes().to_vec(),
+        }
+    }
+
+    pub fn from_proto(proto: &DLogProofProto) -> Result<DLogProof, DLogError> {
+        let commitment = Point::from_bytes(&proto.commitment_bytes).map_err(|_| DLogError::InvalidCommitment)?;
+        if proto.response_bytes.len() != 32 {
+            return Err(DLogError::InvalidResponse);
+        }
+        let response = Scalar::from_bytes(&proto.response_bytes).map_err(|_| DLogError::InvalidResponse)?;
+        Ok(DLogProof::new(commitment, response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+
+    use super::*;
+
+    fn proof() -> DLogProof {
+        let base_point = Point::generator();
+        let private_key = Scalar::random();
+        let public_key = base_point * private_key.clone();
+        DLogProof::generate_proof("session_1", 1, private_key, public_key, base_point.into())
+    }
+
+    #[test]
+    fn test_protobuf_round_trip() {
+        let dlog_proof = proof();
+
+        let encoded = dlog_proof.to_proto().encode_to_vec();
+        let decoded = DLogProofProto::decode(encoded.as_slice()).unwrap();
+        assert_eq!(decoded.commitment_bytes, dlog_proof.to_bytes()[..33]);
+        assert_eq!(DLogProof::from_proto(&decoded).unwrap(), dlog_proof);
+
+        let uncompressed = DLogProofProto {
+            commitment_bytes: dlog_proof.commitment.to_bytes(false).to_vec(),
+            ..decoded
+        };
+        assert_eq!(DLogProof::from_proto(&uncompressed).unwrap(), dlog_proof);
+    }
+
+    #[test]
+    fn test_protobuf_rejects_invalid_bytes() {
+        let proto = proof().to_proto();
+
+        let mut bad_commitment = proto.clone();
+        bad_commitment.commitment_bytes[0] = 0x05;
+        assert_eq!(DLogProof::from_proto(&bad_commitment), Err(DLogError::InvalidCommitment));
+        let empty_commitment = DLogProofProto { commitment_bytes: Vec::new(), ..proto.clone() };
+        assert_eq!(DLogProof::from_proto(&empty_commitment), Err(DLogError::InvalidCommitment));
+
+        let short_response = DLogProofProto { response_bytes: proto.response_bytes[1..].to_vec(), ..proto.clone() };
+        assert_eq!(DLogProof::from_proto(&short_response), Err(DLogError::InvalidResponse));
+        let unreduced = DLogProofProto { response_bytes: vec![0xff; 32], ..proto };
+        assert_eq!(DLogProof::from_proto(&unreduced), Err(DLogError::InvalidResponse));
+    }
+}
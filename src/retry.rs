@@ -0,0 +1,163 @@
+use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+
+use crate::hash::RETRY_DOMAIN;
+use crate::{ChallengeHash, DLogError, DLogProof};
+
+/// A [`DLogProof`] with the retry counter its challenge was derived under.
+///
+/// Counter `0` is the plain [`DLogProof`] challenge, so a proof that needed no retry
+/// also verifies with [`DLogProof::verify_proof`]. Later counters are hashed under their
+/// own domain tag with a length-prefixed session id, then the points and the counter as a
+/// big-endian `u32`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryDLogProof {
+    pub proof: DLogProof,
+    pub counter: u32,
+}
+
+impl RetryDLogProof {
+    /// [`DLogProof::generate_proof`] that, instead of panicking on a zero challenge, bumps
+    /// the counter and rederives it, for at most `max_attempts` challenges in total.
+    /// Fails with [`DLogError::ZeroChallenge`] once they are used up.
+    pub fn generate_proof_retry(
+        max_attempts: u32,
+        session_id: &str,
+        participant_id: i32,
+        private_key: Scalar<Secp256k1>,
+        public_key: Point<Secp256k1>,
+        base_point: Point<Secp256k1>,
+    ) -> Result<RetryDLogProof, DLogError> {
+        RetryDLogProof::generate_with(max_attempts, private_key, &base_point, |commitment, counter| {
+            retry_challenge(session_id, participant_id, &public_key, &base_point, commitment, counter)
+        })
+    }
+
+    fn generate_with(
+        max_attempts: u32,
+        private_key: Scalar<Secp256k1>,
+        base_point: &Point<Secp256k1>,
+        challenge: impl Fn(&Point<Secp256k1>, u32) -> Result<Scalar<Secp256k1>, DLogError>,
+    ) -> Result<RetryDLogProof, DLogError> {
+        let random_scalar = Scalar::random();
+        let commitment = base_point * &random_scalar;
+        for counter in 0..max_attempts {
+            if let Ok(challenge) = challenge(&commitment, counter) {
+                let response = DLogProof::response_for(random_scalar, private_key, &challenge);
+                return Ok(RetryDLogProof { proof: DLogProof::new(commitment, response), counter });
+            }
+        }
+        Err(DLogError::ZeroChallenge)
+    }
+
+    /// Verifies under the carried counter, which is taken as given rather than checked to
+    /// be the first one with a non-zero challenge.
+    pub fn verify_proof(
+        &self,
+        session_id: &str,
+        participant_id: i32,
+        public_key: Point<Secp256k1>,
+        base_point: Point<Secp256k1>,
+    ) -> Result<bool, DLogError> {
+        let challenge = retry_challenge(
+            session_id,
+            participant_id,
+            &public_key,
+            &base_point,
+            &self.proof.commitment,
+            self.counter,
+        )?;
+        self.proof.verify_proof_with_challenge(&challenge, public_key, base_point)
+    }
+}
+
+fn retry_challenge(
+    session_id: &str,
+    participant_id: i32,
+    public_key: &Point<Secp256k1>,
+    base_point: &Point<Secp256k1>,
+    commitment: &Point<Secp256k1>,
+    counter: u32,
+) -> Result<Scalar<Secp256k1>, DLogError> {
+    let participant_id = participant_id.to_be_bytes();
+    let session_len = (session_id.len() as u64).to_be_bytes();
+    let points = [base_point, public_key, commitment].map(|point| point.to_bytes(false));
+    let counter_bytes = counter.to_be_bytes();
+
+    let mut parts: Vec<&[u8]> = if counter == 0 {
+        vec![session_id.as_bytes(), &participant_id]
+    } else {
+        vec![RETRY_DOMAIN, &participant_id, &session_len, session_id.as_bytes()]
+    };
+    parts.extend(points.iter().map(|point| point.as_ref()));
+    if counter > 0 {
+        parts.push(&counter_bytes);
+    }
+    DLogProof::try_challenge_from_digest(&ChallengeHash::DEFAULT.digest(parts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_on_zero_challenge() {
+        let session_id = "session_1";
+        let participant_id = 1;
+
+        let base_point: Point<Secp256k1> = Point::generator().into();
+        let private_key = Scalar::random();
+        let public_key = &base_point * &private_key;
+
+        // Counter 0 is forced to a zero challenge; the retry must move on to counter 1.
+        let zero_first = |commitment: &Point<Secp256k1>, counter| match counter {
+            0 => Err(DLogError::ZeroChallenge),
+            _ => retry_challenge(session_id, participant_id, &public_key, &base_point, commitment, counter),
+        };
+        let retried = RetryDLogProof::generate_with(3, private_key.clone(), &base_point, zero_first).unwrap();
+        assert_eq!(retried.counter, 1);
+        let verify = |proof: &RetryDLogProof| {
+            proof.verify_proof(session_id, participant_id, public_key.clone(), base_point.clone()).unwrap()
+        };
+        assert!(verify(&retried));
+        assert!(!verify(&RetryDLogProof { counter: 0, ..retried.clone() }));
+        assert!(!verify(&RetryDLogProof { counter: 2, ..retried }));
+
+        let always_zero = |_: &Point<Secp256k1>, _| Err(DLogError::ZeroChallenge);
+        assert_eq!(
+            RetryDLogProof::generate_with(3, private_key.clone(), &base_point, always_zero),
+            Err(DLogError::ZeroChallenge)
+        );
+
+        let plain = RetryDLogProof::generate_proof_retry(
+            3,
+            session_id,
+            participant_id,
+            private_key,
+            public_key.clone(),
+            base_point.clone(),
+        )
+        .unwrap();
+        assert_eq!(plain.counter, 0);
+        assert!(plain.proof.verify_proof(session_id, participant_id, public_key, base_point).unwrap());
+    }
+
+    #[test]
+    fn test_retry_counter_is_tagged() {
+        let base_point: Point<Secp256k1> = Point::generator().into();
+        let public_key = &base_point * Scalar::random();
+        let commitment = &base_point * Scalar::random();
+        let points = [&base_point, &public_key, &commitment].map(|point| point.to_bytes(false));
+        let plain_parts = || points.iter().map(|point| point.as_ref());
+
+        assert_eq!(
+            retry_challenge("session_1", 1, &public_key, &base_point, &commitment, 0),
+            DLogProof::hash_challenge(ChallengeHash::DEFAULT, b"session_1", 1, plain_parts())
+        );
+        // Not the plain transcript with the counter appended.
+        let counter = 1u32.to_be_bytes();
+        assert_ne!(
+            retry_challenge("session_1", 1, &public_key, &base_point, &commitment, 1),
+            DLogProof::hash_challenge(ChallengeHash::DEFAULT, b"session_1", 1, plain_parts().chain([&counter[..]]))
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use std::ptr::NonNull;
+
+use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+
+use crate::{DLogError, DLogProof};
+
+/// A private key kept in its own mlocked allocation between guard pages, excluded from
+/// core dumps where the OS supports it. The pages are zeroized and unlocked on drop.
+///
+/// curv does its arithmetic on ordinary [`Scalar`]s, so generating a proof still builds
+/// a short-lived copy outside the locked pages; curv zeroizes that copy when it drops.
+pub struct LockedScalar {
+    bytes: NonNull<[u8; 32]>,
+}
+
+impl LockedScalar {
+    pub fn new(scalar: &Scalar<Secp256k1>) -> Result<LockedScalar, DLogError> {
+        // SAFETY: `malloc` returns either `None` or a writable allocation sized for
+        // `[u8; 32]`, which nothing else references yet.
+        unsafe {
+            let mut bytes = memsec::malloc::<[u8; 32]>().ok_or(DLogError::LockedMemory)?;
+            bytes.as_mut().copy_from_slice(scalar.to_bytes().as_ref());
+            Ok(LockedScalar { bytes })
+        }
+    }
+
+    fn to_scalar(&self) -> Scalar<Secp256k1> {
+        // SAFETY: `bytes` is owned by `self` and stays allocated until drop.
+        Scalar::from_bytes(unsafe { self.bytes.as_ref() }).expect("stored from a canonical scalar")
+    }
+}
+
+impl Drop for LockedScalar {
+    fn drop(&mut self) {
+        // SAFETY: `bytes` came from `memsec::malloc` and is freed exactly once.
+        unsafe { memsec::free(self.bytes) }
+    }
+}
+
+impl DLogProof {
+    /// [`DLogProof::generate_proof`] with the private key read from locked memory.
+    pub fn generate_proof_with_locked_key(
+        session_id: &str,
+        participant_id: i32,
+        private_key: &LockedScalar,
+        public_key: Point<Secp256k1>,
+        base_point: Point<Secp256k1>,
+    ) -> DLogProof {
+        DLogProof::generate_proof(session_id, participant_id, private_key.to_scalar(), public_key, base_point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locked_scalar() {
+        let session_id = "session_1";
+        let participant_id = 1;
+
+        let base_point = Point::generator();
+        let private_key = Scalar::random();
+        let public_key = base_point * private_key.clone();
+
+        let locked = LockedScalar::new(&private_key).unwrap();
+        assert_eq!(locked.to_scalar(), private_key);
+
+        let dlog_proof = DLogProof::generate_proof_with_locked_key(
+            session_id,
+            participant_id,
+            &locked,
+            public_key.clone(),
+            base_point.into(),
+        );
+        drop(locked);
+        assert!(dlog_proof.verify_proof(session_id, participant_id, public_key, base_point.into()).unwrap());
+    }
+}
@@ -0,0 +1,62 @@
+use std::collections::HashSet;
+
+use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+
+use crate::{DLogError, DLogProof};
+
+/// Opt-in wrapper over [`DLogProof::generate_proof`] that refuses to issue a second proof
+/// for a `(session_id, participant_id)` pair it has already issued one for.
+#[derive(Debug, Default)]
+pub struct ProofSession {
+    issued: HashSet<(String, i32)>,
+}
+
+impl ProofSession {
+    pub fn new() -> Self {
+        ProofSession::default()
+    }
+
+    pub fn generate_proof(
+        &mut self,
+        session_id: &str,
+        participant_id: i32,
+        private_key: Scalar<Secp256k1>,
+        public_key: Point<Secp256k1>,
+        base_point: Point<Secp256k1>,
+    ) -> Result<DLogProof, DLogError> {
+        if !self.issued.insert((session_id.to_string(), participant_id)) {
+            return Err(DLogError::ReplayedContext);
+        }
+        Ok(DLogProof::generate_proof(session_id, participant_id, private_key, public_key, base_point))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proof_session_rejects_replay() {
+        let base_point = Point::generator();
+        let private_key = Scalar::random();
+        let public_key = base_point * private_key.clone();
+
+        let mut session = ProofSession::new();
+        let dlog_proof = session
+            .generate_proof("session_1", 1, private_key.clone(), public_key.clone(), base_point.into())
+            .unwrap();
+        assert!(dlog_proof.verify_proof("session_1", 1, public_key.clone(), base_point.into()).unwrap());
+
+        assert_eq!(
+            session.generate_proof("session_1", 1, private_key.clone(), public_key.clone(), base_point.into()),
+            Err(DLogError::ReplayedContext)
+        );
+
+        assert!(session
+            .generate_proof("session_1", 2, private_key.clone(), public_key.clone(), base_point.into())
+            .is_ok());
+        assert!(session
+            .generate_proof("session_2", 1, private_key, public_key, base_point.into())
+            .is_ok());
+    }
+}
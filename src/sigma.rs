@@ -0,0 +1,230 @@
+use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+
+use crate::DLogProof;
+
+/// Common prove/verify interface over the crate's sigma-protocol proofs.
+pub trait SigmaProof {
+    /// Public inputs both sides agree on, including the Fiat-Shamir context.
+    type Statement;
+    /// Secret input known only to the prover.
+    type Witness;
+    type Proof;
+
+    fn prove(statement: &Self::Statement, witness: &Self::Witness) -> Self::Proof;
+
+    fn verify(statement: &Self::Statement, proof: &Self::Proof) -> bool;
+}
+
+/// Statement "I know `x` with `public_key = x·base_point`" in a session.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DLogStatement {
+    pub session_id: String,
+    pub participant_id: i32,
+    pub public_key: Point<Secp256k1>,
+    pub base_point: Point<Secp256k1>,
+}
+
+// Used by `define_sigma!` expansions; not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+
+    use crate::DLogError;
+
+    pub fn challenge(
+        session_id: &str,
+        participant_id: i32,
+        points: Vec<Point<Secp256k1>>,
+    ) -> Result<Scalar<Secp256k1>, DLogError> {
+        crate::DLogProof::compute_challenge(session_id, participant_id, points)
+    }
+
+    pub fn expect_challenge(challenge: Result<Scalar<Secp256k1>, DLogError>) -> Scalar<Secp256k1> {
+        crate::DLogProof::expect_challenge(challenge)
+    }
+}
+
+impl SigmaProof for DLogProof {
+    type Statement = DLogStatement;
+    type Witness = Scalar<Secp256k1>;
+    type Proof = DLogProof;
+
+    fn prove(statement: &DLogStatement, witness: &Scalar<Secp256k1>) -> DLogProof {
+        DLogProof::generate_proof(
+            &statement.session_id,
+            statement.participant_id,
+            witness.clone(),
+            statement.public_key.clone(),
+            statement.base_point.clone(),
+        )
+    }
+
+    fn verify(statement: &DLogStatement, proof: &DLogProof) -> bool {
+        let verified = proof.verify_proof(
+            &statement.session_id,
+            statement.participant_id,
+            statement.public_key.clone(),
+            statement.base_point.clone(),
+        );
+        matches!(verified, Ok(true))
+    }
+}
+
+/// Declares a sigma protocol for a set of linear relations `image = Σ witness·base`.
+///
+/// ```text
+/// define_sigma! {
+///     pub struct RepresentationProof;
+///     statement RepresentationStatement { bases: [g, h], images: [p] }
+///     witness RepresentationWitness { x, y }
+///     relations { p = x * g + y * h; }
+/// }
+/// ```
+///
+/// This expands to a statement struct (`session_id`, `participant_id`, then one point per
+/// base and image), a witness struct (one scalar per witness), and the proof struct, and
+/// implements [`SigmaProof`] for the proof. The proof holds one commitment per relation,
+/// named after its image, and one response per witness, named after the witness. The
+/// challenge is derived like [`DLogProof`]'s, over the bases, then the images, then the
+/// commitments.
+#[macro_export]
+macro_rules! define_sigma {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $proof:ident;
+        statement $statement:ident { bases: [$($base:ident),+ $(,)?], images: [$($image:ident),+ $(,)?] }
+        witness $witness:ident { $($w:ident),+ $(,)? }
+        relations { $($rel_image:ident = $rel_w0:ident * $rel_base0:ident $(+ $rel_w:ident * $rel_base:ident)*;)+ }
+    ) => {
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        $vis struct $statement {
+            pub session_id: String,
+            pub participant_id: i32,
+            $(pub $base: $crate::__private::Point<$crate::__private::Secp256k1>,)+
+            $(pub $image: $crate::__private::Point<$crate::__private::Secp256k1>,)+
+        }
+
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        $vis struct $witness {
+            $(pub $w: $crate::__private::Scalar<$crate::__private::Secp256k1>,)+
+        }
+
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        $vis struct $proof {
+            $(pub $rel_image: $crate::__private::Point<$crate::__private::Secp256k1>,)+
+            $(pub $w: $crate::__private::Scalar<$crate::__private::Secp256k1>,)+
+        }
+
+        impl $crate::SigmaProof for $proof {
+            type Statement = $statement;
+            type Witness = $witness;
+            type Proof = $proof;
+
+            fn prove(statement: &$statement, witness: &$witness) -> $proof {
+                let nonce = $witness {
+                    $($w: $crate::__private::Scalar::random(),)+
+                };
+                let commitments = $proof {
+                    $($rel_image: &statement.$rel_base0 * &nonce.$rel_w0 $(+ &statement.$rel_base * &nonce.$rel_w)*,)+
+                    $($w: $crate::__private::Scalar::zero(),)+
+                };
+                let challenge = $crate::__private::expect_challenge($crate::__private::challenge(
+                    &statement.session_id,
+                    statement.participant_id,
+                    vec![
+                        $(statement.$base.clone(),)+
+                        $(statement.$image.clone(),)+
+                        $(commitments.$rel_image.clone(),)+
+                    ],
+                ));
+                $proof {
+                    $($w: &nonce.$w + &challenge * &witness.$w,)+
+                    ..commitments
+                }
+            }
+
+            fn verify(statement: &$statement, proof: &$proof) -> bool {
+                if false $(|| proof.$rel_image.is_zero())+ {
+                    return false;
+                }
+                let challenge = match $crate::__private::challenge(
+                    &statement.session_id,
+                    statement.participant_id,
+                    vec![
+                        $(statement.$base.clone(),)+
+                        $(statement.$image.clone(),)+
+                        $(proof.$rel_image.clone(),)+
+                    ],
+                ) {
+                    Ok(challenge) => challenge,
+                    Err(_) => return false,
+                };
+                true $(&& (&statement.$rel_base0 * &proof.$rel_w0 $(+ &statement.$rel_base * &proof.$rel_w)*)
+                    == &proof.$rel_image + &statement.$rel_image * &challenge)+
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::define_sigma! {
+        /// Knowledge of `x`, `y` with `p = x·g + y·h`.
+        struct RepresentationProof;
+        statement RepresentationStatement { bases: [g, h], images: [p] }
+        witness RepresentationWitness { x, y }
+        relations { p = x * g + y * h; }
+    }
+
+    fn representation_statement(witness: &RepresentationWitness) -> RepresentationStatement {
+        let g: Point<Secp256k1> = Point::generator().into();
+        let h = Point::base_point2().clone();
+        RepresentationStatement {
+            session_id: "session_1".to_string(),
+            participant_id: 1,
+            p: &g * &witness.x + &h * &witness.y,
+            g,
+            h,
+        }
+    }
+
+    #[test]
+    fn test_define_sigma_two_witnesses() {
+        let witness = RepresentationWitness { x: Scalar::random(), y: Scalar::random() };
+        let statement = representation_statement(&witness);
+
+        let proof = RepresentationProof::prove(&statement, &witness);
+        assert!(RepresentationProof::verify(&statement, &proof));
+
+        let other_session = RepresentationStatement { session_id: "session_2".to_string(), ..statement.clone() };
+        assert!(!RepresentationProof::verify(&other_session, &proof));
+
+        let wrong_witness = RepresentationWitness { x: witness.x.clone(), y: Scalar::random() };
+        let forged = RepresentationProof::prove(&statement, &wrong_witness);
+        assert!(!RepresentationProof::verify(&statement, &forged));
+    }
+
+    #[test]
+    fn test_dlog_proof_through_trait() {
+        let base_point = Point::generator();
+        let private_key = Scalar::random();
+        let statement = DLogStatement {
+            session_id: "session_1".to_string(),
+            participant_id: 1,
+            public_key: base_point * private_key.clone(),
+            base_point: base_point.into(),
+        };
+
+        let dlog_proof = <DLogProof as SigmaProof>::prove(&statement, &private_key);
+        assert!(<DLogProof as SigmaProof>::verify(&statement, &dlog_proof));
+
+        let other_session = DLogStatement { session_id: "session_2".to_string(), ..statement.clone() };
+        assert!(!<DLogProof as SigmaProof>::verify(&other_session, &dlog_proof));
+
+        let wrong_witness = <DLogProof as SigmaProof>::prove(&statement, &Scalar::random());
+        assert!(!<DLogProof as SigmaProof>::verify(&statement, &wrong_witness));
+    }
+}
@@ -0,0 +1,99 @@
+use curv::elliptic::curves::{Generator, Point, Scalar, Secp256k1};
+
+use crate::{DLogError, DLogProof};
+
+/// A public key `private_key·base_point`, kept apart from [`BasePoint`] so the two
+/// cannot be swapped in [`DLogProof::generate_proof_typed`] and
+/// [`DLogProof::verify_proof_typed`]:
+///
+/// ```compile_fail
+/// # use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+/// # use dlog_proof::{BasePoint, DLogProof, PublicKey};
+/// let base_point = BasePoint::from(Point::<Secp256k1>::generator());
+/// let private_key = Scalar::random();
+/// let public_key = PublicKey::from(&base_point.0 * &private_key);
+/// DLogProof::generate_proof_typed("session_1", 1, private_key, base_point, public_key);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicKey(pub Point<Secp256k1>);
+
+/// The base point a proof is made over, usually the curve generator.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BasePoint(pub Point<Secp256k1>);
+
+impl From<Point<Secp256k1>> for PublicKey {
+    fn from(point: Point<Secp256k1>) -> Self {
+        PublicKey(point)
+    }
+}
+
+impl From<Point<Secp256k1>> for BasePoint {
+    fn from(point: Point<Secp256k1>) -> Self {
+        BasePoint(point)
+    }
+}
+
+impl From<Generator<Secp256k1>> for BasePoint {
+    fn from(generator: Generator<Secp256k1>) -> Self {
+        BasePoint(generator.to_point())
+    }
+}
+
+impl DLogProof {
+    /// [`DLogProof::generate_proof`] with the public key and base point typed apart.
+    pub fn generate_proof_typed(
+        session_id: &str,
+        participant_id: i32,
+        private_key: Scalar<Secp256k1>,
+        public_key: PublicKey,
+        base_point: BasePoint,
+    ) -> DLogProof {
+        DLogProof::generate_proof(session_id, participant_id, private_key, public_key.0, base_point.0)
+    }
+
+    /// [`DLogProof::verify_proof`] with the public key and base point typed apart.
+    pub fn verify_proof_typed(
+        &self,
+        session_id: &str,
+        participant_id: i32,
+        public_key: PublicKey,
+        base_point: BasePoint,
+    ) -> Result<bool, DLogError> {
+        self.verify_proof(session_id, participant_id, public_key.0, base_point.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_typed_arguments() {
+        let session_id = "session_1";
+        let participant_id = 1;
+
+        let base_point = BasePoint::from(Point::generator());
+        let private_key = Scalar::random();
+        let public_key = PublicKey::from(&base_point.0 * &private_key);
+
+        let dlog_proof = DLogProof::generate_proof_typed(
+            session_id,
+            participant_id,
+            private_key,
+            public_key.clone(),
+            base_point.clone(),
+        );
+        assert_eq!(
+            dlog_proof.verify_proof_typed(session_id, participant_id, public_key.clone(), base_point.clone()),
+            Ok(true)
+        );
+        assert_eq!(
+            dlog_proof.verify_proof(session_id, participant_id, public_key.0.clone(), base_point.0.clone()),
+            Ok(true)
+        );
+
+        // The same points with their roles exchanged, which the raw API accepts silently.
+        let swapped = (PublicKey(base_point.0), BasePoint(public_key.0));
+        assert_eq!(dlog_proof.verify_proof_typed(session_id, participant_id, swapped.0, swapped.1), Ok(false));
+    }
+}